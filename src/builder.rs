@@ -0,0 +1,214 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::dialect::Dialect;
+use crate::error::Error;
+use crate::{PartType, PrereleaseComponent, Version};
+
+/// Construct a version from its individual parts, without parsing a version string.
+///
+/// The prerelease identifier and build metadata are validated against the rules of the chosen dialect
+/// when the version is built.
+///
+/// ```
+/// use smvr::{Dialect, PrereleaseComponent, Version};
+///
+/// let version = Version::builder()
+///     .major(1)
+///     .prerelease(vec![
+///         PrereleaseComponent::String("rc".to_string()),
+///         PrereleaseComponent::Number(1),
+///     ])
+///     .build_metadata("exp.sha.5114f85")
+///     .dialect(Dialect::Standard)
+///     .build()?;
+///
+/// assert_eq!(version.to_string(), "1.0.0-rc.1+exp.sha.5114f85");
+/// # Ok::<(), smvr::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct VersionBuilder {
+    major: usize,
+    minor: usize,
+    patch: usize,
+    prerelease: Option<Vec<PrereleaseComponent>>,
+    build_metadata: Option<String>,
+    dialect: Dialect,
+}
+
+impl Default for VersionBuilder {
+    fn default() -> Self {
+        VersionBuilder {
+            major: 0,
+            minor: 0,
+            patch: 0,
+            prerelease: None,
+            build_metadata: None,
+            dialect: Dialect::Standard,
+        }
+    }
+}
+
+impl VersionBuilder {
+    /// Set the major version.
+    pub fn major(mut self, major: usize) -> Self {
+        self.major = major;
+        self
+    }
+
+    /// Set the minor version.
+    pub fn minor(mut self, minor: usize) -> Self {
+        self.minor = minor;
+        self
+    }
+
+    /// Set the patch version.
+    pub fn patch(mut self, patch: usize) -> Self {
+        self.patch = patch;
+        self
+    }
+
+    /// Set the components of the prerelease identifier.
+    pub fn prerelease(mut self, components: Vec<PrereleaseComponent>) -> Self {
+        self.prerelease = Some(components);
+        self
+    }
+
+    /// Set the build metadata.
+    pub fn build_metadata(mut self, build_metadata: &str) -> Self {
+        self.build_metadata = Some(build_metadata.to_string());
+        self
+    }
+
+    /// Set the dialect the version follows. Defaults to the standard dialect.
+    pub fn dialect(mut self, dialect: Dialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// Validate the provided parts against the dialect, and build the version.
+    pub fn build(self) -> Result<Version, Error> {
+        if let Some(prerelease) = &self.prerelease {
+            for component in prerelease {
+                if let PrereleaseComponent::String(component) = component {
+                    Version::validate_part(
+                        component.as_bytes(),
+                        PartType::Prerelease,
+                        self.dialect,
+                    )?;
+                }
+            }
+        }
+
+        if let Some(build_metadata) = &self.build_metadata {
+            Version::validate_part(
+                build_metadata.as_bytes(),
+                PartType::BuildMetadata,
+                self.dialect,
+            )?;
+        }
+
+        Ok(Version::new(
+            self.major,
+            self.minor,
+            self.patch,
+            self.prerelease.filter(|prerelease| !prerelease.is_empty()),
+            self.build_metadata,
+            None,
+            self.dialect,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    use crate::{Dialect, Error, PartType, PrereleaseComponent, Version};
+
+    #[test]
+    fn should_build_version_which_round_trips() {
+        let version = Version::builder()
+            .major(1)
+            .prerelease(vec![
+                PrereleaseComponent::String("rc".to_string()),
+                PrereleaseComponent::Number(1),
+            ])
+            .build_metadata("exp.sha.5114f85")
+            .build()
+            .unwrap();
+
+        assert_eq!(version.to_string(), "1.0.0-rc.1+exp.sha.5114f85");
+        assert_eq!(
+            version,
+            Version::parse("1.0.0-rc.1+exp.sha.5114f85", Dialect::Standard).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_build_version_with_dialect() {
+        let version = Version::builder()
+            .major(1)
+            .minor(2)
+            .patch(3)
+            .dialect(Dialect::Cargo)
+            .build()
+            .unwrap();
+
+        assert_eq!(version, Version::parse("1.2.3", Dialect::Cargo).unwrap());
+    }
+
+    #[test]
+    fn should_fail_invalid_prerelease() {
+        let version = Version::builder()
+            .prerelease(vec![PrereleaseComponent::String("has space".to_string())])
+            .build();
+
+        assert_eq!(
+            version,
+            Err(Error::InvalidCharacter {
+                part: PartType::Prerelease,
+                byte: b' ',
+                position: 3
+            })
+        );
+    }
+
+    #[test]
+    fn should_fail_dotted_prerelease_component() {
+        let version = Version::builder()
+            .prerelease(vec![PrereleaseComponent::String("alpha.1".to_string())])
+            .build();
+
+        assert_eq!(
+            version,
+            Err(Error::InvalidCharacter {
+                part: PartType::Prerelease,
+                byte: b'.',
+                position: 5
+            })
+        );
+    }
+
+    #[test]
+    fn should_fail_empty_build_metadata() {
+        let version = Version::builder().build_metadata("").build();
+
+        assert_eq!(version, Err(Error::EmptyPart(PartType::BuildMetadata)));
+    }
+
+    #[test]
+    fn should_fail_invalid_build_metadata() {
+        let version = Version::builder().build_metadata("build+1").build();
+
+        assert_eq!(
+            version,
+            Err(Error::InvalidCharacter {
+                part: PartType::BuildMetadata,
+                byte: b'+',
+                position: 5
+            })
+        );
+    }
+}
@@ -0,0 +1,171 @@
+/// Controls which parts of a version are kept when it's bumped (like with
+/// [`Version::bump_patch_with`](crate::Version::bump_patch_with)).
+///
+/// The default follows SemVer, clearing both the prerelease identifier and build metadata, which is
+/// the same as the `bump_*` methods.
+///
+/// ```
+/// use smvr::{BumpOptions, Dialect, Version};
+/// let version = Version::parse("1.2.3+sha.abc", Dialect::Standard)?;
+/// let options = BumpOptions {
+///     keep_build_metadata: true,
+///     ..BumpOptions::default()
+/// };
+///
+/// assert_eq!(version.bump_patch_with(options)?.to_string(), "1.2.4+sha.abc");
+/// assert_eq!(version.bump_patch_with(BumpOptions::default())?.to_string(), "1.2.4");
+/// # Ok::<(), smvr::Error>(())
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct BumpOptions {
+    /// Keep the prerelease identifier (like `rc.1`) on the bumped version.
+    pub keep_prerelease: bool,
+    /// Keep the build metadata (like `sha.abc`) on the bumped version.
+    pub keep_build_metadata: bool,
+}
+
+/// The level a version is incremented by, which can be stored as data (like a release level chosen
+/// from command line input) and applied with [`Version::apply_increment`](crate::Version::apply_increment).
+///
+/// ```
+/// use smvr::{Dialect, Increment, Version};
+/// let version = Version::parse("1.2.3", Dialect::Standard)?;
+/// assert_eq!(version.apply_increment(Increment::Minor)?.to_string(), "1.3.0");
+/// # Ok::<(), smvr::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Increment {
+    /// Increment the major version, like [`Version::bump_major`](crate::Version::bump_major).
+    Major,
+    /// Increment the minor version, like [`Version::bump_minor`](crate::Version::bump_minor).
+    Minor,
+    /// Increment the patch version, like [`Version::bump_patch`](crate::Version::bump_patch).
+    Patch,
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::{BumpOptions, Dialect, Error, Increment, PartType, Version};
+
+    const CLEAR: BumpOptions = BumpOptions {
+        keep_prerelease: false,
+        keep_build_metadata: false,
+    };
+
+    #[test]
+    fn should_clear_everything_by_default() {
+        let version = Version::parse("1.2.3-rc.1+sha.abc", Dialect::Standard).unwrap();
+
+        assert_eq!(BumpOptions::default(), CLEAR);
+        assert_eq!(
+            version.bump_major_with(CLEAR).unwrap(),
+            version.bump_major()
+        );
+        assert_eq!(
+            version.bump_minor_with(CLEAR).unwrap().to_string(),
+            version.bump_minor().to_string()
+        );
+        assert_eq!(version.bump_patch_with(CLEAR).unwrap().to_string(), "1.2.4");
+    }
+
+    #[test]
+    fn should_keep_build_metadata() {
+        let version = Version::parse("1.2.3-rc.1+sha.abc", Dialect::Standard).unwrap();
+        let options = BumpOptions {
+            keep_build_metadata: true,
+            ..BumpOptions::default()
+        };
+
+        assert_eq!(
+            version.bump_major_with(options).unwrap().to_string(),
+            "2.0.0+sha.abc"
+        );
+        assert_eq!(
+            version.bump_minor_with(options).unwrap().to_string(),
+            "1.3.0+sha.abc"
+        );
+        assert_eq!(
+            version.bump_patch_with(options).unwrap().to_string(),
+            "1.2.4+sha.abc"
+        );
+    }
+
+    #[test]
+    fn should_keep_prerelease() {
+        let version = Version::parse("v1.2.3-rc.1+sha.abc", Dialect::Npm).unwrap();
+        let options = BumpOptions {
+            keep_prerelease: true,
+            ..BumpOptions::default()
+        };
+
+        assert_eq!(
+            version.bump_patch_with(options).unwrap().to_string(),
+            "v1.2.4-rc.1"
+        );
+    }
+
+    #[test]
+    fn should_keep_prerelease_and_build_metadata() {
+        let version = Version::parse("1.2.3-rc.1+sha.abc", Dialect::Standard).unwrap();
+        let options = BumpOptions {
+            keep_prerelease: true,
+            keep_build_metadata: true,
+        };
+
+        assert_eq!(
+            version.bump_minor_with(options).unwrap().to_string(),
+            "1.3.0-rc.1+sha.abc"
+        );
+    }
+
+    #[test]
+    fn should_fail_on_overflow() {
+        let version = Version::builder().patch(usize::MAX).build().unwrap();
+
+        assert!(version.bump_patch_with(BumpOptions::default()).is_err());
+    }
+
+    #[test]
+    fn should_apply_increment_parsed_from_input() {
+        let increment = |level: &str| match level {
+            "major" => Some(Increment::Major),
+            "minor" => Some(Increment::Minor),
+            "patch" => Some(Increment::Patch),
+            _ => None,
+        };
+        let version = Version::parse("1.2.3", Dialect::Standard).unwrap();
+
+        assert_eq!(
+            version
+                .apply_increment(increment("minor").unwrap())
+                .unwrap()
+                .to_string(),
+            "1.3.0"
+        );
+    }
+
+    #[test]
+    fn should_apply_each_increment() {
+        let version = Version::parse("1.2.3-rc.1+build", Dialect::Standard).unwrap();
+
+        for (increment, bumped) in [
+            (Increment::Major, version.bump_major()),
+            (Increment::Minor, version.bump_minor()),
+            (Increment::Patch, version.bump_patch()),
+        ] {
+            assert_eq!(version.apply_increment(increment).unwrap(), bumped);
+        }
+    }
+
+    #[test]
+    fn should_fail_increment_on_overflow() {
+        let version = Version::builder().minor(usize::MAX).build().unwrap();
+
+        assert_eq!(
+            version.apply_increment(Increment::Minor),
+            Err(Error::NumberOverflow(PartType::Minor))
+        );
+    }
+}
@@ -1,8 +1,87 @@
 use alloc::string::String;
+use alloc::vec::Vec;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 /// The build metadata for a particular version.
 pub enum BuildMetadata {
     Empty,
     Identifier(String),
 }
+
+impl BuildMetadata {
+    /// The dot-separated identifiers which make up the build metadata.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let version = Version::parse("1.0.0+exp.sha.5114f85", Dialect::Standard)?;
+    /// assert_eq!(version.build_metadata.components(), vec!["exp", "sha", "5114f85"]);
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn components(&self) -> Vec<&str> {
+        match self {
+            BuildMetadata::Empty => Vec::new(),
+            BuildMetadata::Identifier(identifier) => identifier.split('.').collect(),
+        }
+    }
+
+    /// Replace the build metadata identifier, clearing the build metadata when it's empty.
+    ///
+    /// The identifier isn't validated against the version's dialect.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let mut version = Version::parse("1.0.0+old", Dialect::Standard)?;
+    /// version.build_metadata.set("exp.sha.5114f85".to_string());
+    /// assert_eq!(version.to_string(), "1.0.0+exp.sha.5114f85");
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn set(&mut self, identifier: String) {
+        *self = if identifier.is_empty() {
+            BuildMetadata::Empty
+        } else {
+            BuildMetadata::Identifier(identifier)
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use crate::{BuildMetadata, Dialect, Version};
+
+    #[test]
+    fn components_are_split_on_dots() {
+        let version = Version::parse("1.0.0+exp.sha.5114f85", Dialect::Standard).unwrap();
+
+        assert_eq!(
+            version.build_metadata.components(),
+            vec!["exp", "sha", "5114f85"]
+        );
+        assert_eq!(version.to_string(), "1.0.0+exp.sha.5114f85");
+    }
+
+    #[test]
+    fn set_replaces_identifier() {
+        let mut build_metadata = BuildMetadata::Empty;
+
+        build_metadata.set("build.1".to_string());
+        assert_eq!(
+            build_metadata,
+            BuildMetadata::Identifier("build.1".to_string())
+        );
+
+        build_metadata.set("build.2".to_string());
+        assert_eq!(build_metadata.components(), vec!["build", "2"]);
+
+        build_metadata.set(String::new());
+        assert_eq!(build_metadata, BuildMetadata::Empty);
+    }
+
+    #[test]
+    fn empty_build_metadata_has_no_components() {
+        assert_eq!(BuildMetadata::Empty.components(), Vec::<&str>::new());
+    }
+}
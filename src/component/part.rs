@@ -1,7 +1,10 @@
 use core::fmt::{Display, Formatter};
 
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 /// The component parts of a SemVer version.
+///
+/// Parts are ordered in the order they appear in a version string (`Major` < `Minor` < `Patch` <
+/// `Prerelease` < `BuildMetadata`).
 pub enum PartType {
     /// The major version number in a version string.
     ///
@@ -30,6 +33,26 @@ pub enum PartType {
     BuildMetadata,
 }
 
+impl PartType {
+    /// The part which follows this one in a version string, or `None` for the build metadata, which is
+    /// always the last part.
+    ///
+    /// ```
+    /// use smvr::PartType;
+    /// assert_eq!(PartType::Major.next(), Some(PartType::Minor));
+    /// assert_eq!(PartType::BuildMetadata.next(), None);
+    /// ```
+    pub fn next(self) -> Option<PartType> {
+        match self {
+            PartType::Major => Some(PartType::Minor),
+            PartType::Minor => Some(PartType::Patch),
+            PartType::Patch => Some(PartType::Prerelease),
+            PartType::Prerelease => Some(PartType::BuildMetadata),
+            PartType::BuildMetadata => None,
+        }
+    }
+}
+
 impl Display for PartType {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(
@@ -45,3 +68,40 @@ impl Display for PartType {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use crate::PartType;
+
+    #[test]
+    fn should_transition_through_every_part() {
+        let chain =
+            core::iter::successors(Some(PartType::Major), |part| part.next()).collect::<Vec<_>>();
+
+        assert_eq!(
+            chain,
+            vec![
+                PartType::Major,
+                PartType::Minor,
+                PartType::Patch,
+                PartType::Prerelease,
+                PartType::BuildMetadata
+            ]
+        );
+    }
+
+    #[test]
+    fn should_not_transition_from_build_metadata() {
+        assert_eq!(PartType::BuildMetadata.next(), None);
+    }
+
+    #[test]
+    fn should_order_parts_by_position() {
+        assert!(PartType::Major < PartType::Minor);
+        assert!(PartType::Patch < PartType::Prerelease);
+        assert!(PartType::Prerelease < PartType::BuildMetadata);
+    }
+}
@@ -1,23 +1,238 @@
-use alloc::string::String;
+use alloc::string::{String, ToString};
+use alloc::vec;
 use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::fmt::{Display, Formatter};
 
-#[derive(Debug, PartialEq, PartialOrd)]
+use crate::component::PartType;
+use crate::dialect::Dialect;
+use crate::error::Error;
+use crate::Version;
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
 /// The prerelease metadata for a particular version.
 ///
 /// If provided, the identifier is broken down into one or more prerelease components.
+///
+/// Prereleases are ordered following their precedence, meaning an empty prerelease is **greater** than
+/// any prerelease identifier (i.e. `1.0.0-alpha` < `1.0.0`).
 pub enum Prerelease {
     Empty,
     Identifier(Vec<PrereleaseComponent>),
 }
 
-#[derive(Debug, PartialEq, PartialOrd, Clone)]
+impl Prerelease {
+    /// Parse a prerelease identifier on its own (like `alpha.1` in `1.0.0-alpha.1`), following a
+    /// specific Semver dialect.
+    ///
+    /// The identifier is split on dots, and each component is validated and interpreted the same as
+    /// when parsing a full version. An empty string is parsed as [`Prerelease::Empty`], but an empty
+    /// component (like in `alpha..1`) returns an [`Error::EmptyPart`] error. The position of an
+    /// [`Error::InvalidCharacter`] error is relative to the start of the identifier.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Prerelease, PrereleaseComponent};
+    /// let prerelease = Prerelease::parse("alpha.1", Dialect::Standard)?;
+    /// assert_eq!(
+    ///     prerelease.components(),
+    ///     &[PrereleaseComponent::String("alpha".to_string()), PrereleaseComponent::Number(1)]
+    /// );
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn parse(prerelease: &str, dialect: Dialect) -> Result<Prerelease, Error> {
+        if prerelease.is_empty() {
+            return Ok(Prerelease::Empty);
+        }
+
+        let mut position = 0;
+        let mut components = vec![];
+
+        for component in prerelease.split('.') {
+            Version::validate_part(component.as_bytes(), PartType::Prerelease, dialect).map_err(
+                |error| match error {
+                    Error::InvalidCharacter {
+                        part,
+                        byte,
+                        position: offset,
+                    } => Error::InvalidCharacter {
+                        part,
+                        byte,
+                        position: position + offset,
+                    },
+                    error => error,
+                },
+            )?;
+
+            components.push(Version::parse_prerelease_component(component.as_bytes())?);
+            position += component.len() + 1;
+        }
+
+        Ok(Prerelease::Identifier(components))
+    }
+
+    /// The components which make up the prerelease identifier, or an empty slice when there
+    /// is no prerelease.
+    ///
+    /// ```
+    /// use smvr::{Dialect, PrereleaseComponent, Version};
+    /// let version = Version::parse("1.0.0-rc.1", Dialect::Standard)?;
+    /// assert_eq!(
+    ///     version.prerelease.components(),
+    ///     &[PrereleaseComponent::String("rc".to_string()), PrereleaseComponent::Number(1)]
+    /// );
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn components(&self) -> &[PrereleaseComponent] {
+        match self {
+            Prerelease::Empty => &[],
+            Prerelease::Identifier(components) => components,
+        }
+    }
+
+    /// The number of components in the prerelease identifier.
+    pub fn len(&self) -> usize {
+        self.components().len()
+    }
+
+    /// Whether there are no components in the prerelease identifier.
+    pub fn is_empty(&self) -> bool {
+        self.components().is_empty()
+    }
+
+    /// Append a component to the end of the prerelease identifier, starting a new identifier when
+    /// there is no prerelease.
+    ///
+    /// The component isn't validated against the version's dialect.
+    ///
+    /// ```
+    /// use smvr::{Dialect, PrereleaseComponent, Version};
+    /// let mut version = Version::parse("1.0.0", Dialect::Standard)?;
+    /// version.prerelease.push(PrereleaseComponent::new("rc")?);
+    /// version.prerelease.push(PrereleaseComponent::Number(1));
+    /// assert_eq!(version.to_string(), "1.0.0-rc.1");
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn push(&mut self, component: PrereleaseComponent) {
+        match self {
+            Prerelease::Empty => *self = Prerelease::Identifier(vec![component]),
+            Prerelease::Identifier(components) => components.push(component),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Prerelease {
+    type Item = &'a PrereleaseComponent;
+    type IntoIter = core::slice::Iter<'a, PrereleaseComponent>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.components().iter()
+    }
+}
+
+impl PartialOrd for Prerelease {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Prerelease {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Prerelease::Empty, Prerelease::Empty) => Ordering::Equal,
+            (Prerelease::Empty, Prerelease::Identifier(_)) => Ordering::Greater,
+            (Prerelease::Identifier(_), Prerelease::Empty) => Ordering::Less,
+            (Prerelease::Identifier(a), Prerelease::Identifier(b)) => a.cmp(b),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Hash)]
 /// An individual piece of a prerelease identifier, as they were interpreted.
+///
+/// Components are ordered following the SemVer precedence rules: numeric components are compared
+/// numerically, alphanumeric components are compared in ASCII sort order, and numeric components
+/// always have lower precedence than alphanumeric ones. The derived ordering relies on `Number` being
+/// declared before `String`.
 pub enum PrereleaseComponent {
     Number(usize),
     String(String),
 }
 
+impl PrereleaseComponent {
+    /// Create a component from a single prerelease identifier (like `alpha` or `1`), validating it
+    /// following the SemVer rules.
+    ///
+    /// Identifiers may only contain ASCII alphanumerics and hyphens. Identifiers made up entirely of
+    /// digits are numeric, and must not include leading zeros.
+    ///
+    /// ```
+    /// use smvr::{Error, PartType, PrereleaseComponent};
+    /// assert_eq!(PrereleaseComponent::new("rc")?, PrereleaseComponent::String("rc".to_string()));
+    /// assert_eq!(PrereleaseComponent::new("10")?, PrereleaseComponent::Number(10));
+    /// assert_eq!(
+    ///     PrereleaseComponent::new("01"),
+    ///     Err(Error::InvalidPrecedingZero(PartType::Prerelease))
+    /// );
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn new(component: &str) -> Result<PrereleaseComponent, Error> {
+        if component.is_empty() {
+            return Err(Error::EmptyPart(PartType::Prerelease));
+        }
+
+        if let Some(position) = component
+            .bytes()
+            .position(|byte| !byte.is_ascii_alphanumeric() && byte != b'-')
+        {
+            return Err(Error::InvalidCharacter {
+                part: PartType::Prerelease,
+                byte: component.as_bytes()[position],
+                position,
+            });
+        }
+
+        if !component.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Ok(PrereleaseComponent::String(component.to_string()));
+        }
+
+        if component.len() > 1 && component.starts_with('0') {
+            return Err(Error::InvalidPrecedingZero(PartType::Prerelease));
+        }
+
+        component
+            .parse()
+            .map(PrereleaseComponent::Number)
+            .map_err(|_| Error::NumberOverflow(PartType::Prerelease))
+    }
+
+    /// Whether the component is numeric.
+    ///
+    /// ```
+    /// use smvr::PrereleaseComponent;
+    /// assert!(PrereleaseComponent::Number(1).is_numeric());
+    /// assert!(!PrereleaseComponent::String("rc".to_string()).is_numeric());
+    /// ```
+    pub fn is_numeric(&self) -> bool {
+        matches!(self, PrereleaseComponent::Number(_))
+    }
+
+    /// The value of the component, when it's numeric.
+    pub fn as_number(&self) -> Option<usize> {
+        match self {
+            PrereleaseComponent::Number(number) => Some(*number),
+            PrereleaseComponent::String(_) => None,
+        }
+    }
+
+    /// The value of the component, when it's alphanumeric.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            PrereleaseComponent::Number(_) => None,
+            PrereleaseComponent::String(string) => Some(string),
+        }
+    }
+}
+
 impl Display for PrereleaseComponent {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match self {
@@ -26,3 +241,248 @@ impl Display for PrereleaseComponent {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use crate::{Dialect, Error, PartType, Prerelease, PrereleaseComponent};
+
+    #[test]
+    fn empty_prerelease_is_greater_than_identifier() {
+        let identifier = Prerelease::Identifier(vec![
+            PrereleaseComponent::String("alpha".to_string()),
+            PrereleaseComponent::Number(1),
+        ]);
+
+        assert!(Prerelease::Empty > identifier);
+        assert!(identifier < Prerelease::Empty);
+        assert_eq!(Prerelease::Empty, Prerelease::Empty);
+    }
+
+    #[test]
+    fn identifiers_are_ordered_by_component() {
+        let alpha = Prerelease::Identifier(vec![PrereleaseComponent::String("alpha".to_string())]);
+        let alpha_1 = Prerelease::Identifier(vec![
+            PrereleaseComponent::String("alpha".to_string()),
+            PrereleaseComponent::Number(1),
+        ]);
+        let beta = Prerelease::Identifier(vec![PrereleaseComponent::String("beta".to_string())]);
+
+        assert!(alpha < alpha_1);
+        assert!(alpha_1 < beta);
+    }
+
+    #[test]
+    fn empty_prerelease_has_no_components() {
+        let prerelease = Prerelease::Empty;
+
+        assert!(prerelease.components().is_empty());
+        assert_eq!(prerelease.len(), 0);
+        assert!(prerelease.is_empty());
+        assert_eq!((&prerelease).into_iter().count(), 0);
+    }
+
+    #[test]
+    fn identifier_components_can_be_iterated() {
+        let prerelease = Prerelease::Identifier(vec![
+            PrereleaseComponent::String("alpha".to_string()),
+            PrereleaseComponent::Number(1),
+            PrereleaseComponent::String("beta".to_string()),
+        ]);
+
+        assert_eq!(prerelease.len(), 3);
+        assert!(!prerelease.is_empty());
+        assert_eq!(prerelease.components()[1], PrereleaseComponent::Number(1));
+
+        let mut rendered = vec![];
+        for component in &prerelease {
+            rendered.push(component.to_string());
+        }
+
+        assert_eq!(rendered, vec!["alpha", "1", "beta"]);
+    }
+
+    #[test]
+    fn parse_splits_identifier_into_components() {
+        assert_eq!(
+            Prerelease::parse("alpha.1", Dialect::Standard).unwrap(),
+            Prerelease::Identifier(vec![
+                PrereleaseComponent::String("alpha".to_string()),
+                PrereleaseComponent::Number(1),
+            ])
+        );
+        assert_eq!(
+            Prerelease::parse("rc.0a.-x.10", Dialect::Cargo)
+                .unwrap()
+                .components()
+                .iter()
+                .map(|component| component.to_string())
+                .collect::<Vec<_>>(),
+            vec!["rc", "0a", "-x", "10"]
+        );
+    }
+
+    #[test]
+    fn parse_empty_identifier() {
+        assert_eq!(
+            Prerelease::parse("", Dialect::Standard).unwrap(),
+            Prerelease::Empty
+        );
+    }
+
+    #[test]
+    fn parse_rejects_invalid_identifier() {
+        for (prerelease, error) in [
+            ("alpha..1", Error::EmptyPart(PartType::Prerelease)),
+            (".alpha", Error::EmptyPart(PartType::Prerelease)),
+            ("alpha.", Error::EmptyPart(PartType::Prerelease)),
+            (
+                "alpha.01",
+                Error::InvalidPrecedingZero(PartType::Prerelease),
+            ),
+            (
+                "alpha.b$ta",
+                Error::InvalidCharacter {
+                    part: PartType::Prerelease,
+                    byte: b'$',
+                    position: 7,
+                },
+            ),
+            (
+                "alpha+build",
+                Error::InvalidCharacter {
+                    part: PartType::Prerelease,
+                    byte: b'+',
+                    position: 5,
+                },
+            ),
+        ] {
+            assert_eq!(
+                Prerelease::parse(prerelease, Dialect::Standard),
+                Err(error),
+                "{}",
+                prerelease
+            );
+        }
+    }
+
+    #[test]
+    fn push_builds_identifier_from_empty() {
+        let mut prerelease = Prerelease::Empty;
+
+        prerelease.push(PrereleaseComponent::String("alpha".to_string()));
+        prerelease.push(PrereleaseComponent::Number(1));
+        prerelease.push(PrereleaseComponent::Number(2));
+
+        assert_eq!(
+            prerelease,
+            Prerelease::Identifier(vec![
+                PrereleaseComponent::String("alpha".to_string()),
+                PrereleaseComponent::Number(1),
+                PrereleaseComponent::Number(2),
+            ])
+        );
+    }
+
+    #[test]
+    fn numeric_component_accessors() {
+        let component = PrereleaseComponent::Number(7);
+
+        assert!(component.is_numeric());
+        assert_eq!(component.as_number(), Some(7));
+        assert_eq!(component.as_str(), None);
+    }
+
+    #[test]
+    fn string_component_accessors() {
+        let component = PrereleaseComponent::String("beta".to_string());
+
+        assert!(!component.is_numeric());
+        assert_eq!(component.as_number(), None);
+        assert_eq!(component.as_str(), Some("beta"));
+    }
+
+    #[test]
+    fn components_can_be_filtered_with_accessors() {
+        let prerelease = Prerelease::Identifier(vec![
+            PrereleaseComponent::String("alpha".to_string()),
+            PrereleaseComponent::Number(1),
+            PrereleaseComponent::String("beta".to_string()),
+            PrereleaseComponent::Number(2),
+        ]);
+
+        let numbers = prerelease
+            .into_iter()
+            .filter_map(PrereleaseComponent::as_number)
+            .collect::<Vec<_>>();
+        let labels = prerelease
+            .into_iter()
+            .filter_map(PrereleaseComponent::as_str)
+            .collect::<Vec<_>>();
+
+        assert_eq!(numbers, vec![1, 2]);
+        assert_eq!(labels, vec!["alpha", "beta"]);
+    }
+
+    #[test]
+    fn new_component_from_valid_identifier() {
+        assert_eq!(
+            PrereleaseComponent::new("alpha-1").unwrap(),
+            PrereleaseComponent::String("alpha-1".to_string())
+        );
+        assert_eq!(
+            PrereleaseComponent::new("0a").unwrap(),
+            PrereleaseComponent::String("0a".to_string())
+        );
+    }
+
+    #[test]
+    fn new_component_detects_numeric_identifier() {
+        assert_eq!(
+            PrereleaseComponent::new("0").unwrap(),
+            PrereleaseComponent::Number(0)
+        );
+        assert_eq!(
+            PrereleaseComponent::new("42").unwrap(),
+            PrereleaseComponent::Number(42)
+        );
+    }
+
+    #[test]
+    fn new_component_rejects_invalid_identifier() {
+        for (component, error) in [
+            ("", Error::EmptyPart(PartType::Prerelease)),
+            (
+                "has space",
+                Error::InvalidCharacter {
+                    part: PartType::Prerelease,
+                    byte: b' ',
+                    position: 3,
+                },
+            ),
+            (
+                "a.b",
+                Error::InvalidCharacter {
+                    part: PartType::Prerelease,
+                    byte: b'.',
+                    position: 1,
+                },
+            ),
+            ("007", Error::InvalidPrecedingZero(PartType::Prerelease)),
+            (
+                "99999999999999999999999",
+                Error::NumberOverflow(PartType::Prerelease),
+            ),
+        ] {
+            assert_eq!(
+                PrereleaseComponent::new(component),
+                Err(error),
+                "{}",
+                component
+            );
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use core::cmp::Ordering;
+
+use crate::dialect::{DialectParser, Standard};
+use crate::{BuildMetadata, Version};
+
+pub struct Cargo;
+
+impl DialectParser for Cargo {
+    /// Compare two versions following the ordering used by Cargo's resolver.
+    ///
+    /// Precedence is identical to the standard dialect, but when two versions share the same
+    /// precedence the build metadata is used as a final tie-break, so that the ordering is total.
+    ///
+    /// Prereleases aren't ordered any differently (`1.2.3-alpha` is still lower than `1.2.3`), as
+    /// Cargo's resolver only excludes them when matching requirements.
+    fn cmp(a: &Version, b: &Version) -> Ordering {
+        Standard::cmp(a, b).then_with(|| compare_build_metadata(a, b))
+    }
+
+    /// Compare two versions and decide if they're considered equal.
+    ///
+    /// Unlike the standard dialect, build metadata is significant when deciding equality.
+    fn eq(a: &Version, b: &Version) -> bool {
+        Standard::eq(a, b) && a.build_metadata.eq(&b.build_metadata)
+    }
+
+    /// Following Cargo, a bare version requirement (like `1.2.3`) is a caret requirement.
+    fn is_bare_requirement_caret() -> bool {
+        true
+    }
+}
+
+/// Order build metadata identifiers one dot-separated identifier at a time.
+///
+/// Numeric identifiers are compared numerically and are always lower than alphanumeric identifiers,
+/// which are compared lexically. A version without build metadata is lower than one with it.
+fn compare_build_metadata(a: &Version, b: &Version) -> Ordering {
+    match (&a.build_metadata, &b.build_metadata) {
+        (BuildMetadata::Empty, BuildMetadata::Empty) => Ordering::Equal,
+        (BuildMetadata::Empty, BuildMetadata::Identifier(_)) => Ordering::Less,
+        (BuildMetadata::Identifier(_), BuildMetadata::Empty) => Ordering::Greater,
+        (BuildMetadata::Identifier(a), BuildMetadata::Identifier(b)) => {
+            let mut b = b.split('.');
+
+            for a in a.split('.') {
+                let b = match b.next() {
+                    Some(b) => b,
+                    None => return Ordering::Greater,
+                };
+
+                let is_numeric = |identifier: &str| identifier.bytes().all(|i| i.is_ascii_digit());
+
+                let ordering = match (is_numeric(a), is_numeric(b)) {
+                    (true, true) => {
+                        let (trimmed_a, trimmed_b) =
+                            (a.trim_start_matches('0'), b.trim_start_matches('0'));
+
+                        trimmed_a
+                            .len()
+                            .cmp(&trimmed_b.len())
+                            .then_with(|| trimmed_a.cmp(trimmed_b))
+                            .then_with(|| a.len().cmp(&b.len()))
+                    }
+                    (true, false) => Ordering::Less,
+                    (false, true) => Ordering::Greater,
+                    (false, false) => a.cmp(b),
+                };
+
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+
+            if b.next().is_none() {
+                Ordering::Equal
+            } else {
+                Ordering::Less
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cmp::Ordering;
+
+    use crate::{Dialect, Requirement, Version};
+
+    #[test]
+    fn should_share_precedence_with_standard() {
+        let versions = [
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1.0.0-rc.1",
+            "1.0.0",
+            "1.2.3+build",
+        ];
+
+        for a in versions {
+            for b in versions {
+                let standard = Version::parse(a, Dialect::Standard)
+                    .unwrap()
+                    .partial_cmp(&Version::parse(b, Dialect::Standard).unwrap());
+                let cargo = Version::parse(a, Dialect::Cargo)
+                    .unwrap()
+                    .partial_cmp(&Version::parse(b, Dialect::Cargo).unwrap());
+
+                assert_eq!(standard, cargo, "{} {}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn should_diverge_from_standard_on_build_metadata() {
+        let standard_a = Version::parse("1.2.3-alpha+build.1", Dialect::Standard).unwrap();
+        let standard_b = Version::parse("1.2.3-alpha+build.2", Dialect::Standard).unwrap();
+        let cargo_a = Version::parse("1.2.3-alpha+build.1", Dialect::Cargo).unwrap();
+        let cargo_b = Version::parse("1.2.3-alpha+build.2", Dialect::Cargo).unwrap();
+
+        assert_eq!(standard_a, standard_b);
+        assert_eq!(standard_a.partial_cmp(&standard_b), Some(Ordering::Equal));
+
+        assert_ne!(cargo_a, cargo_b);
+        assert_eq!(cargo_a.partial_cmp(&cargo_b), Some(Ordering::Less));
+    }
+
+    #[test]
+    fn should_order_numeric_build_metadata_numerically() {
+        let build_9 = Version::parse("1.2.3+9", Dialect::Cargo).unwrap();
+        let build_10 = Version::parse("1.2.3+10", Dialect::Cargo).unwrap();
+        let build_alpha = Version::parse("1.2.3+alpha", Dialect::Cargo).unwrap();
+        let no_build = Version::parse("1.2.3", Dialect::Cargo).unwrap();
+
+        assert!(no_build < build_9);
+        assert!(build_9 < build_10);
+        assert!(build_10 < build_alpha);
+    }
+
+    #[test]
+    fn should_order_by_dialect_across_dialects() {
+        let standard = Version::parse("1.2.3", Dialect::Standard).unwrap();
+        let cargo = Version::parse("1.2.3", Dialect::Cargo).unwrap();
+
+        assert_ne!(standard, cargo);
+        assert_eq!(standard.partial_cmp(&cargo), Some(Ordering::Less));
+    }
+
+    #[test]
+    fn should_ignore_build_metadata_when_matching_requirements() {
+        let version = Version::parse("1.2.3+build", Dialect::Cargo).unwrap();
+
+        for requirement in ["=1.2.3", "<=1.2.3", ">=1.2.3", "1.2.3", "=1.2.3+other"] {
+            assert!(
+                Requirement::parse(requirement, Dialect::Cargo)
+                    .unwrap()
+                    .matches(&version),
+                "{}",
+                requirement
+            );
+        }
+
+        for requirement in ["<1.2.3", ">1.2.3"] {
+            assert!(
+                !Requirement::parse(requirement, Dialect::Cargo)
+                    .unwrap()
+                    .matches(&version),
+                "{}",
+                requirement
+            );
+        }
+    }
+}
@@ -0,0 +1,572 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::component::PartType;
+use crate::dialect::cursor::Cursor;
+use crate::dialect::{Dialect, DialectParser, Extension};
+use crate::error::Error;
+use crate::{BuildMetadata, PrereleaseComponent, Version};
+
+pub struct Composer;
+
+/// The parts of a Composer version which can't be represented by the standard version parts.
+///
+/// The `alpha`, `beta` and `RC` stabilities (and `dev`) are represented by the prerelease identifier
+/// (like `beta.2.dev`), as they sort before the stable release.
+///
+/// The stability flag (like `@beta`) only tells Composer which stabilities to accept when resolving
+/// the version, so it's kept for formatting but doesn't take part in the ordering.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Release {
+    revision: usize,
+    patch: Option<Vec<usize>>,
+    flag: Option<Stability>,
+}
+
+/// The stability of a version, in order of precedence.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Stability {
+    Dev,
+    Alpha,
+    Beta,
+    ReleaseCandidate,
+    Stable,
+    Patch,
+}
+
+impl Stability {
+    /// The normalized label used for the stability.
+    fn label(&self) -> &'static str {
+        match self {
+            Stability::Dev => "dev",
+            Stability::Alpha => "alpha",
+            Stability::Beta => "beta",
+            Stability::ReleaseCandidate => "RC",
+            Stability::Stable => "stable",
+            Stability::Patch => "patch",
+        }
+    }
+
+    /// The stability named by a stability flag (like `@beta`), which can be any stability except
+    /// `patch`.
+    fn from_flag(flag: &str) -> Option<Stability> {
+        [
+            Stability::Dev,
+            Stability::Alpha,
+            Stability::Beta,
+            Stability::ReleaseCandidate,
+            Stability::Stable,
+        ]
+        .into_iter()
+        .find(|stability| stability.label().eq_ignore_ascii_case(flag))
+    }
+}
+
+/// A complete view of a Composer version, used for comparing and formatting.
+struct Parts {
+    segments: (usize, usize, usize, usize),
+    stability: Stability,
+    numbers: Vec<usize>,
+    dev: bool,
+    flag: Option<Stability>,
+}
+
+impl DialectParser for Composer {
+    const PARSES_WHOLE_STRING: bool = true;
+
+    /// Parse a version string following the Composer version format.
+    ///
+    /// Versions may begin with a `v` prefix, and have between one and four numeric segments, where
+    /// any missing segments are normalized to zero (so `1.0` is parsed as `1.0.0`). A stability suffix
+    /// (like `-alpha1`, `-beta.2`, `-RC1` or `-patch1`) and a `-dev` suffix may follow, and are
+    /// matched case-insensitively. The version may end with a stability flag (like `@beta`).
+    fn parse(version: &str, dialect: Dialect) -> Result<Version, Error> {
+        let normalized = version.to_ascii_lowercase();
+        let (version, flag) = match version
+            .rsplit_once('@')
+            .and_then(|(version, flag)| Some((version, Stability::from_flag(flag)?)))
+        {
+            Some((version, flag)) => (version, Some(flag)),
+            None => (version, None),
+        };
+        let mut cursor = Cursor {
+            bytes: &normalized.as_bytes()[..version.len()],
+            position: 0,
+            leading_whitespace: 0,
+        };
+
+        cursor.eat(b"v");
+
+        let mut segments = vec![cursor.required_number(PartType::Major)?];
+
+        while segments.len() < 4
+            && cursor.peek() == Some(b'.')
+            && cursor.peek_at(1).is_some_and(|b| b.is_ascii_digit())
+        {
+            cursor.position += 1;
+            segments.push(cursor.required_number(match segments.len() {
+                1 => PartType::Minor,
+                _ => PartType::Patch,
+            })?);
+        }
+
+        let stability = cursor.optional(|cursor| {
+            cursor.eat_separator();
+
+            let stability = if cursor.eat(b"stable") {
+                Stability::Stable
+            } else if cursor.eat(b"beta") || cursor.eat(b"b") {
+                Stability::Beta
+            } else if cursor.eat(b"rc") {
+                Stability::ReleaseCandidate
+            } else if cursor.eat(b"alpha") || cursor.eat(b"a") {
+                Stability::Alpha
+            } else if cursor.eat(b"patch") || cursor.eat(b"pl") || cursor.eat(b"p") {
+                Stability::Patch
+            } else {
+                return Ok(None);
+            };
+
+            let mut numbers = vec![];
+            while let Some(number) = cursor.optional(|cursor| {
+                let _ = cursor.eat(b".") || cursor.eat(b"-");
+
+                cursor.number(PartType::Prerelease)
+            })? {
+                numbers.push(number);
+            }
+
+            Ok(Some((stability, numbers)))
+        })?;
+
+        let dev = cursor
+            .optional(|cursor| {
+                let _ = cursor.eat(b".") || cursor.eat(b"-");
+
+                Ok(cursor.eat(b"dev").then_some(()))
+            })?
+            .is_some();
+
+        let build_metadata = if cursor.eat(b"+") {
+            let position = cursor.offset();
+            let build_metadata = &version[position..];
+
+            if build_metadata.split('.').any(str::is_empty) {
+                return Err(Error::EmptyPart(PartType::BuildMetadata));
+            }
+
+            if let Some(i) = build_metadata
+                .bytes()
+                .position(|byte| !byte.is_ascii_alphanumeric() && byte != b'-' && byte != b'.')
+            {
+                return Err(Error::InvalidCharacter {
+                    part: PartType::BuildMetadata,
+                    byte: build_metadata.as_bytes()[i],
+                    position: position + i,
+                });
+            }
+
+            Some(build_metadata.to_string())
+        } else if let Some(byte) = cursor.peek() {
+            let is_modifier = stability.is_some() || dev || matches!(byte, b'-' | b'_' | b'.');
+
+            return Err(Error::InvalidCharacter {
+                part: if is_modifier {
+                    PartType::Prerelease
+                } else {
+                    match segments.len() {
+                        1 => PartType::Major,
+                        2 => PartType::Minor,
+                        _ => PartType::Patch,
+                    }
+                },
+                byte,
+                position: cursor.offset(),
+            });
+        } else {
+            None
+        };
+
+        let (stability, patch) = match stability {
+            Some((Stability::Patch, numbers)) => (None, Some(numbers)),
+            Some((Stability::Stable, _)) => (None, None),
+            stability => (stability, None),
+        };
+
+        let mut version = Version::new(
+            segments[0],
+            segments.get(1).copied().unwrap_or(0),
+            segments.get(2).copied().unwrap_or(0),
+            prerelease_components(stability, dev),
+            build_metadata,
+            None,
+            dialect,
+        );
+        version.extension = Some(Extension::Composer(Release {
+            revision: segments.get(3).copied().unwrap_or(0),
+            patch,
+            flag,
+        }));
+
+        Ok(version)
+    }
+
+    /// Compare two versions following Composer's stability precedence.
+    ///
+    /// For example: `1.0.0-dev` < `1.0.0-alpha1` < `1.0.0-beta1-dev` < `1.0.0-beta1` < `1.0.0-RC1`
+    /// < `1.0.0` < `1.0.0-patch1` < `1.0.1`
+    fn cmp(a: &Version, b: &Version) -> Ordering {
+        let (a, b) = (parts(a), parts(b));
+
+        a.segments
+            .cmp(&b.segments)
+            .then_with(|| a.stability.cmp(&b.stability))
+            .then_with(|| a.numbers.cmp(&b.numbers))
+            .then_with(|| b.dev.cmp(&a.dev))
+    }
+
+    /// Compare two versions and decide if they're considered equal, following Composer's stability
+    /// precedence.
+    fn eq(a: &Version, b: &Version) -> bool {
+        Composer::cmp(a, b) == Ordering::Equal
+    }
+
+    /// Normalize a version into its Composer form, without the build metadata or stability flag.
+    fn canonicalize(version: &Version) -> String {
+        let mut string = Composer::format(version);
+
+        // Only the build metadata can contain a plus, and only the stability flag can contain an at
+        if let Some(position) = string.find(['+', '@']) {
+            string.truncate(position);
+        }
+
+        string
+    }
+
+    /// Format a version back into its normalized Composer form.
+    ///
+    /// The fourth numeric segment is only included when it's not zero, and the stability flag is
+    /// kept at the end.
+    fn format(version: &Version) -> String {
+        let parts = parts(version);
+        let (major, minor, patch, revision) = parts.segments;
+        let mut string = format!("{}.{}.{}", major, minor, patch);
+
+        if revision > 0 {
+            string.push_str(&format!(".{}", revision));
+        }
+
+        match parts.stability {
+            Stability::Dev => string.push_str("-dev"),
+            Stability::Stable => {}
+            stability => {
+                string.push_str(&format!(
+                    "-{}{}",
+                    stability.label(),
+                    parts
+                        .numbers
+                        .iter()
+                        .map(|number| number.to_string())
+                        .collect::<Vec<_>>()
+                        .join(".")
+                ));
+
+                if parts.dev {
+                    string.push_str("-dev");
+                }
+            }
+        }
+
+        if let BuildMetadata::Identifier(identifier) = &version.build_metadata {
+            string.push_str(&format!("+{}", identifier));
+        }
+
+        if let Some(flag) = parts.flag {
+            string.push_str(&format!("@{}", flag.label()));
+        }
+
+        string
+    }
+}
+
+/// Represent the unstable stabilities as prerelease components, so that they're visible as a
+/// prerelease identifier (like `beta.2.dev`).
+fn prerelease_components(
+    stability: Option<(Stability, Vec<usize>)>,
+    dev: bool,
+) -> Option<Vec<PrereleaseComponent>> {
+    let mut components = vec![];
+
+    if let Some((stability, numbers)) = stability {
+        components.push(PrereleaseComponent::String(stability.label().to_string()));
+        components.extend(numbers.into_iter().map(PrereleaseComponent::Number));
+    }
+
+    if dev {
+        components.push(PrereleaseComponent::String(
+            Stability::Dev.label().to_string(),
+        ));
+    }
+
+    if components.is_empty() {
+        None
+    } else {
+        Some(components)
+    }
+}
+
+/// Interpret the complete Composer parts of a version.
+///
+/// Versions which weren't parsed using the dialect (for example, when bumping a version) are
+/// interpreted from their standard parts alone.
+fn parts(version: &Version) -> Parts {
+    let release = match &version.extension {
+        Some(Extension::Composer(release)) => release.clone(),
+        _ => Release {
+            revision: 0,
+            patch: None,
+            flag: None,
+        },
+    };
+
+    let mut components = version.prerelease.components();
+    let mut dev = false;
+
+    if let Some((PrereleaseComponent::String(label), rest)) = components.split_last() {
+        if label == Stability::Dev.label() {
+            dev = true;
+            components = rest;
+        }
+    }
+
+    let labelled = match components.split_first() {
+        Some((PrereleaseComponent::String(label), numbers)) => [
+            Stability::Alpha,
+            Stability::Beta,
+            Stability::ReleaseCandidate,
+        ]
+        .into_iter()
+        .find(|stability| stability.label() == label)
+        .map(|stability| {
+            (
+                stability,
+                numbers
+                    .iter()
+                    .filter_map(PrereleaseComponent::as_number)
+                    .collect(),
+            )
+        }),
+        _ => None,
+    };
+
+    let (stability, numbers, dev) = match (labelled, release.patch) {
+        (Some((stability, numbers)), _) => (stability, numbers, dev),
+        (None, Some(numbers)) => (Stability::Patch, numbers, dev),
+        // A version which is only a development version (like 1.0.0-dev) sorts before every stability
+        (None, None) if dev => (Stability::Dev, vec![], false),
+        (None, None) => (Stability::Stable, vec![], false),
+    };
+
+    Parts {
+        segments: (
+            version.major,
+            version.minor,
+            version.patch,
+            release.revision,
+        ),
+        stability,
+        numbers,
+        dev,
+        flag: release.flag,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::{Dialect, Error, PartType, Version};
+
+    #[test]
+    fn should_parse_prefix_and_normalize_missing_segments() {
+        let version = Version::parse("v1.2", Dialect::Composer).unwrap();
+
+        assert_eq!(version.core(), (1, 2, 0));
+        assert_eq!(version.to_string(), "1.2.0");
+        assert_eq!(
+            version,
+            Version::parse("1.2.0.0", Dialect::Composer).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_parse_fourth_segment() {
+        let version = Version::parse("1.2.3.4", Dialect::Composer).unwrap();
+
+        assert_eq!(version.to_string(), "1.2.3.4");
+        assert!(version > Version::parse("1.2.3", Dialect::Composer).unwrap());
+    }
+
+    #[test]
+    fn should_normalize_stability_suffixes() {
+        for (version, normalized) in [
+            ("1.0.0-ALPHA1", "1.0.0-alpha1"),
+            ("1.0.0a1", "1.0.0-alpha1"),
+            ("1.0.0-b.2", "1.0.0-beta2"),
+            ("1.0.0-rc1", "1.0.0-RC1"),
+            ("1.0.0-RC", "1.0.0-RC"),
+            ("1.0.0-pl1", "1.0.0-patch1"),
+            ("1.0.0-beta2-dev", "1.0.0-beta2-dev"),
+            ("1.0.0-DEV", "1.0.0-dev"),
+            ("1.0.0-stable", "1.0.0"),
+            ("v2.0.0-beta.1+build.5", "2.0.0-beta1+build.5"),
+        ] {
+            assert_eq!(
+                Version::parse(version, Dialect::Composer)
+                    .unwrap()
+                    .to_string(),
+                normalized,
+                "{}",
+                version
+            );
+        }
+    }
+
+    #[test]
+    fn should_treat_unstable_versions_as_prereleases() {
+        assert!(Version::parse("1.0.0-dev", Dialect::Composer)
+            .unwrap()
+            .is_prerelease());
+        assert!(Version::parse("1.0.0-RC1", Dialect::Composer)
+            .unwrap()
+            .is_prerelease());
+        assert!(!Version::parse("1.0.0-patch1", Dialect::Composer)
+            .unwrap()
+            .is_prerelease());
+    }
+
+    #[test]
+    fn should_order_by_stability() {
+        let versions = [
+            "1.0.0-dev",
+            "1.0.0-alpha",
+            "1.0.0-alpha2",
+            "1.0.0-beta",
+            "1.0.0-beta1-dev",
+            "1.0.0-beta1",
+            "1.0.0-RC",
+            "1.0.0",
+            "1.0.0-patch1",
+            "1.0.1-dev",
+            "1.0.1",
+        ];
+
+        for pair in versions.windows(2) {
+            let a = Version::parse(pair[0], Dialect::Composer).unwrap();
+            let b = Version::parse(pair[1], Dialect::Composer).unwrap();
+
+            assert!(a < b, "{} < {}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn should_keep_stability_flag_outside_of_ordering() {
+        for (version, formatted, canonical) in [
+            ("1.0.0@beta", "1.0.0@beta", "1.0.0"),
+            ("v1.0-RC1@rc", "1.0.0-RC1@RC", "1.0.0-RC1"),
+            ("1.0.0+build@DEV", "1.0.0+build@dev", "1.0.0"),
+            ("1.0.0-dev@stable", "1.0.0-dev@stable", "1.0.0-dev"),
+        ] {
+            let version = Version::parse(version, Dialect::Composer).unwrap();
+
+            assert_eq!(version.to_string(), formatted);
+            assert_eq!(version.canonicalize(), canonical);
+            assert_eq!(
+                version,
+                Version::parse(canonical, Dialect::Composer).unwrap()
+            );
+        }
+
+        assert!(
+            Version::parse("1.0.0-beta1@dev", Dialect::Composer).unwrap()
+                < Version::parse("1.0.0@beta", Dialect::Composer).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_fail_invalid_versions() {
+        for (version, error) in [
+            ("", Error::EmptyPart(PartType::Major)),
+            (
+                "dev-master",
+                Error::InvalidCharacter {
+                    part: PartType::Major,
+                    byte: b'd',
+                    position: 0,
+                },
+            ),
+            (
+                "1.2x",
+                Error::InvalidCharacter {
+                    part: PartType::Minor,
+                    byte: b'x',
+                    position: 3,
+                },
+            ),
+            (
+                "1.0.0-gamma",
+                Error::InvalidCharacter {
+                    part: PartType::Prerelease,
+                    byte: b'-',
+                    position: 5,
+                },
+            ),
+            (
+                "1.0.0-beta1x",
+                Error::InvalidCharacter {
+                    part: PartType::Prerelease,
+                    byte: b'x',
+                    position: 11,
+                },
+            ),
+            ("1.0.0+", Error::EmptyPart(PartType::BuildMetadata)),
+            ("1.0.0+a..b", Error::EmptyPart(PartType::BuildMetadata)),
+            ("1.0.0+x.", Error::EmptyPart(PartType::BuildMetadata)),
+            ("0+.", Error::EmptyPart(PartType::BuildMetadata)),
+            (
+                "1.0.0@patch",
+                Error::InvalidCharacter {
+                    part: PartType::Patch,
+                    byte: b'@',
+                    position: 5,
+                },
+            ),
+            (
+                "1.0.0+a$b",
+                Error::InvalidCharacter {
+                    part: PartType::BuildMetadata,
+                    byte: b'$',
+                    position: 7,
+                },
+            ),
+        ] {
+            assert_eq!(
+                Version::parse(version, Dialect::Composer),
+                Err(error),
+                "{}",
+                version
+            );
+        }
+    }
+
+    #[test]
+    fn should_interpret_bumped_versions() {
+        let version = Version::parse("1.0.0.1-RC1", Dialect::Composer).unwrap();
+
+        assert_eq!(version.bump_minor().to_string(), "1.1.0");
+        assert!(version < version.bump_patch());
+    }
+}
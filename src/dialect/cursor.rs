@@ -0,0 +1,94 @@
+use crate::component::PartType;
+use crate::error::Error;
+
+/// A simple cursor over the bytes of a version string.
+pub(crate) struct Cursor<'a> {
+    pub(crate) bytes: &'a [u8],
+    pub(crate) position: usize,
+    pub(crate) leading_whitespace: usize,
+}
+
+impl<'a> Cursor<'a> {
+    /// The position of the cursor inside the original (untrimmed) version string.
+    pub(crate) fn offset(&self) -> usize {
+        self.leading_whitespace + self.position
+    }
+
+    pub(crate) fn peek(&self) -> Option<u8> {
+        self.peek_at(0)
+    }
+
+    pub(crate) fn peek_at(&self, offset: usize) -> Option<u8> {
+        self.bytes.get(self.position + offset).copied()
+    }
+
+    pub(crate) fn remaining(&self) -> &'a [u8] {
+        &self.bytes[self.position..]
+    }
+
+    /// Consume the literal, if the remaining bytes start with it.
+    pub(crate) fn eat(&mut self, literal: &[u8]) -> bool {
+        if self.remaining().starts_with(literal) {
+            self.position += literal.len();
+
+            return true;
+        }
+
+        false
+    }
+
+    /// Consume a single separator (`-`, `_` or `.`), if one is next.
+    pub(crate) fn eat_separator(&mut self) -> bool {
+        self.eat(b"-") || self.eat(b"_") || self.eat(b".")
+    }
+
+    /// Consume a run of digits, if there are any.
+    pub(crate) fn number(&mut self, part: PartType) -> Result<Option<usize>, Error> {
+        let digits = self
+            .remaining()
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count();
+
+        if digits == 0 {
+            return Ok(None);
+        }
+
+        let number = core::str::from_utf8(&self.remaining()[..digits])
+            .map_err(|_| Error::InvalidUtf8(part))?
+            .parse::<usize>()
+            .map_err(|_| Error::NumberOverflow(part))?;
+
+        self.position += digits;
+
+        Ok(Some(number))
+    }
+
+    /// Consume a run of digits, which must be present.
+    pub(crate) fn required_number(&mut self, part: PartType) -> Result<usize, Error> {
+        match (self.number(part)?, self.peek()) {
+            (Some(number), _) => Ok(number),
+            (None, None) => Err(Error::EmptyPart(part)),
+            (None, Some(byte)) => Err(Error::InvalidCharacter {
+                part,
+                byte,
+                position: self.offset(),
+            }),
+        }
+    }
+
+    /// Attempt to parse an optional section of the version, rewinding if it's not present.
+    pub(crate) fn optional<T>(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<Option<T>, Error>,
+    ) -> Result<Option<T>, Error> {
+        let position = self.position;
+        let parsed = parse(self)?;
+
+        if parsed.is_none() {
+            self.position = position;
+        }
+
+        Ok(parsed)
+    }
+}
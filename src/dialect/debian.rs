@@ -0,0 +1,540 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::cmp::Ordering;
+
+use crate::component::PartType;
+use crate::dialect::{Dialect, DialectParser, Extension};
+use crate::error::Error;
+use crate::Version;
+
+pub struct Debian;
+
+/// The parts of a Debian version which can't be represented by the standard major, minor and patch
+/// versions.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Release {
+    epoch: usize,
+    upstream: String,
+    revision: Option<String>,
+
+    /// The major, minor and patch versions read from the upstream version, so that changes to
+    /// them (like bumping the version) can be detected.
+    core: (usize, usize, usize),
+}
+
+impl DialectParser for Debian {
+    const PARSES_WHOLE_STRING: bool = true;
+
+    /// Parse a version string following the Debian version format (`[epoch:]upstream[-revision]`).
+    ///
+    /// The upstream version must begin with a digit. Its leading numeric segments are used as the
+    /// major, minor and patch versions (like `1`, `2` and `0` in `1:1.2~rc1-3`), while the epoch, full
+    /// upstream version and revision are kept so that versions can be compared and formatted faithfully.
+    ///
+    /// Errors in the epoch and upstream version are reported against the major part, and errors in the
+    /// revision are reported against the build metadata part.
+    fn parse(version: &str, dialect: Dialect) -> Result<Version, Error> {
+        let (epoch, upstream_start) = match version.find(':') {
+            Some(colon) => {
+                let epoch = &version[..colon];
+
+                if epoch.is_empty() {
+                    return Err(Error::EmptyPart(PartType::Major));
+                }
+
+                if let Some(i) = epoch.bytes().position(|byte| !byte.is_ascii_digit()) {
+                    return Err(Error::InvalidCharacter {
+                        part: PartType::Major,
+                        byte: epoch.as_bytes()[i],
+                        position: i,
+                    });
+                }
+
+                let epoch = epoch
+                    .parse::<usize>()
+                    .map_err(|_| Error::NumberOverflow(PartType::Major))?;
+
+                (epoch, colon + 1)
+            }
+            None => (0, 0),
+        };
+
+        let (upstream, revision) = match version[upstream_start..].rfind('-') {
+            Some(hyphen) => {
+                let hyphen = upstream_start + hyphen;
+
+                (
+                    &version[upstream_start..hyphen],
+                    Some((&version[hyphen + 1..], hyphen + 1)),
+                )
+            }
+            None => (&version[upstream_start..], None),
+        };
+
+        validate_upstream(upstream, upstream_start)?;
+
+        if let Some((revision, revision_start)) = revision {
+            validate_revision(revision, revision_start)?;
+        }
+
+        let core = leading_numbers(upstream)?;
+
+        let mut version = Version::new(core.0, core.1, core.2, None, None, None, dialect);
+        version.extension = Some(Extension::Debian(Release {
+            epoch,
+            upstream: upstream.to_string(),
+            revision: revision.map(|(revision, _)| revision.to_string()),
+            core,
+        }));
+
+        Ok(version)
+    }
+
+    /// Skip the epoch (like `1:` in `1:2.0-1`), as it's not part of the upstream version.
+    fn strip_epoch(version: &str) -> &str {
+        version
+            .split_once(':')
+            .map_or(version, |(_, release)| release)
+    }
+
+    /// Report a different epoch as the major version, as it's more significant than the upstream
+    /// version.
+    fn diff(a: &Version, b: &Version) -> Option<PartType> {
+        (release(a).epoch != release(b).epoch).then_some(PartType::Major)
+    }
+
+    /// Keep the epoch, as changing the upstream version doesn't move a version out of its epoch. The
+    /// upstream version is replaced by the changed major, minor and patch versions, and the revision
+    /// no longer applies.
+    fn retain_extension(version: &Version, changed: &Version) -> Option<Extension> {
+        match &version.extension {
+            Some(Extension::Debian(release)) => Some(Extension::Debian(Release {
+                epoch: release.epoch,
+                ..upstream_release(changed)
+            })),
+            _ => None,
+        }
+    }
+
+    /// Compare two versions following the algorithm used by `dpkg`.
+    ///
+    /// The epoch is compared first, followed by the upstream version and the revision. Each of these
+    /// strings is compared in alternating runs of non-digits (compared character by character, where
+    /// `~` sorts before everything, even the end of the string) and digits (compared numerically).
+    ///
+    /// For example: `1.0~rc1` < `1.0` < `1.0-1` < `1.0a` < `1:0.1`
+    fn cmp(a: &Version, b: &Version) -> Ordering {
+        let (a, b) = (release(a), release(b));
+
+        a.epoch
+            .cmp(&b.epoch)
+            .then_with(|| compare_strings(&a.upstream, &b.upstream))
+            .then_with(|| {
+                compare_strings(
+                    a.revision.as_deref().unwrap_or_default(),
+                    b.revision.as_deref().unwrap_or_default(),
+                )
+            })
+    }
+
+    /// Compare two versions and decide if they're considered equal, following the algorithm used by `dpkg`.
+    fn eq(a: &Version, b: &Version) -> bool {
+        Debian::cmp(a, b) == Ordering::Equal
+    }
+
+    /// Normalize a version into the Debian version format, so that versions which `dpkg` considers
+    /// equal are identical (like `0:1.01-0` and `1.1`).
+    ///
+    /// A zero epoch is omitted, as is a revision equal to zero.
+    fn canonicalize(version: &Version) -> String {
+        let release = release(version);
+        let mut string = String::new();
+
+        if release.epoch > 0 {
+            string.push_str(&format!("{}:", release.epoch));
+        }
+
+        string.push_str(&normalize(&release.upstream));
+
+        match release.revision.as_deref().map(normalize).as_deref() {
+            None | Some("0") => {}
+            Some(revision) => string.push_str(&format!("-{}", revision)),
+        }
+
+        string
+    }
+
+    /// Format a version back into the Debian version format, omitting a zero epoch.
+    fn format(version: &Version) -> String {
+        let release = release(version);
+        let mut string = String::new();
+
+        if release.epoch > 0 {
+            string.push_str(&format!("{}:", release.epoch));
+        }
+
+        string.push_str(&release.upstream);
+
+        if let Some(revision) = release.revision {
+            string.push_str(&format!("-{}", revision));
+        }
+
+        string
+    }
+}
+
+/// Normalize part of a Debian version, so that parts which `dpkg` considers equal are identical.
+///
+/// Every run of digits is written without leading zeros, and a missing run of digits is written as
+/// zero (so `1.01a` is normalized to `1.1a0`), as `dpkg` treats both the same as zero.
+fn normalize(string: &str) -> String {
+    let bytes = string.as_bytes();
+    let mut normalized = String::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let start = i;
+
+        while bytes.get(i).is_some_and(|byte| !byte.is_ascii_digit()) {
+            i += 1;
+        }
+
+        normalized.push_str(&string[start..i]);
+
+        let start = i;
+
+        while bytes.get(i).is_some_and(|byte| byte.is_ascii_digit()) {
+            i += 1;
+        }
+
+        match string[start..i].trim_start_matches('0') {
+            "" => normalized.push('0'),
+            digits => normalized.push_str(digits),
+        }
+    }
+
+    normalized
+}
+
+/// The upstream version must start with a digit, and only contain alphanumerics and the characters
+/// `.`, `+`, `~` and `-`.
+fn validate_upstream(upstream: &str, position: usize) -> Result<(), Error> {
+    match upstream.bytes().next() {
+        None => return Err(Error::EmptyPart(PartType::Major)),
+        Some(byte) if !byte.is_ascii_digit() => {
+            return Err(Error::InvalidCharacter {
+                part: PartType::Major,
+                byte,
+                position,
+            })
+        }
+        _ => {}
+    }
+
+    if let Some(i) = upstream.bytes().position(|byte| {
+        !byte.is_ascii_alphanumeric() && !matches!(byte, b'.' | b'+' | b'~' | b'-')
+    }) {
+        return Err(Error::InvalidCharacter {
+            part: PartType::Major,
+            byte: upstream.as_bytes()[i],
+            position: position + i,
+        });
+    }
+
+    Ok(())
+}
+
+/// The revision must not be empty, and only contain alphanumerics and the characters `.`, `+` and `~`.
+fn validate_revision(revision: &str, position: usize) -> Result<(), Error> {
+    if revision.is_empty() {
+        return Err(Error::EmptyPart(PartType::BuildMetadata));
+    }
+
+    if let Some(i) = revision
+        .bytes()
+        .position(|byte| !byte.is_ascii_alphanumeric() && !matches!(byte, b'.' | b'+' | b'~'))
+    {
+        return Err(Error::InvalidCharacter {
+            part: PartType::BuildMetadata,
+            byte: revision.as_bytes()[i],
+            position: position + i,
+        });
+    }
+
+    Ok(())
+}
+
+/// Read up to three leading dot-separated numbers from the upstream version, to use as the major, minor
+/// and patch versions (like `1.2` from `1.2~rc1`).
+fn leading_numbers(upstream: &str) -> Result<(usize, usize, usize), Error> {
+    let mut numbers = [0; 3];
+    let mut remaining = upstream;
+
+    for (i, part) in [PartType::Major, PartType::Minor, PartType::Patch]
+        .into_iter()
+        .enumerate()
+    {
+        let digits = remaining
+            .bytes()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count();
+
+        if digits == 0 {
+            break;
+        }
+
+        numbers[i] = remaining[..digits]
+            .parse::<usize>()
+            .map_err(|_| Error::NumberOverflow(part))?;
+
+        match remaining[digits..].strip_prefix('.') {
+            Some(rest) => remaining = rest,
+            None => break,
+        }
+    }
+
+    Ok((numbers[0], numbers[1], numbers[2]))
+}
+
+/// The Debian parts of a version.
+///
+/// Versions which weren't parsed using the Debian dialect, or whose major, minor or patch versions have
+/// since been changed, use their major, minor and patch versions as the upstream version.
+fn release(version: &Version) -> Release {
+    match &version.extension {
+        Some(Extension::Debian(release)) if release.core == version.core() => release.clone(),
+        _ => upstream_release(version),
+    }
+}
+
+/// The Debian parts of a version which only has its major, minor and patch versions, without an epoch
+/// or revision.
+fn upstream_release(version: &Version) -> Release {
+    Release {
+        epoch: 0,
+        upstream: format!("{}.{}.{}", version.major, version.minor, version.patch),
+        revision: None,
+        core: version.core(),
+    }
+}
+
+/// Compare two strings following the algorithm used by `dpkg`, alternating between runs of non-digits
+/// and runs of digits.
+fn compare_strings(a: &str, b: &str) -> Ordering {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let (mut i, mut j) = (0, 0);
+
+    while i < a.len() || j < b.len() {
+        while a.get(i).is_some_and(|byte| !byte.is_ascii_digit())
+            || b.get(j).is_some_and(|byte| !byte.is_ascii_digit())
+        {
+            let ordering = weight(a.get(i)).cmp(&weight(b.get(j)));
+
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+
+            i += 1;
+            j += 1;
+        }
+
+        while a.get(i) == Some(&b'0') {
+            i += 1;
+        }
+
+        while b.get(j) == Some(&b'0') {
+            j += 1;
+        }
+
+        let mut first_difference = Ordering::Equal;
+
+        while let (Some(x), Some(y)) = (a.get(i), b.get(j)) {
+            if !x.is_ascii_digit() || !y.is_ascii_digit() {
+                break;
+            }
+
+            if first_difference == Ordering::Equal {
+                first_difference = x.cmp(y);
+            }
+
+            i += 1;
+            j += 1;
+        }
+
+        // The longer run of digits (once leading zeros are skipped) is the larger number
+        if a.get(i).is_some_and(|byte| byte.is_ascii_digit()) {
+            return Ordering::Greater;
+        }
+
+        if b.get(j).is_some_and(|byte| byte.is_ascii_digit()) {
+            return Ordering::Less;
+        }
+
+        if first_difference != Ordering::Equal {
+            return first_difference;
+        }
+    }
+
+    Ordering::Equal
+}
+
+/// The weight of a single non-digit character, where `~` sorts before the end of the string (and digits),
+/// which sort before letters, which sort before all other characters.
+fn weight(byte: Option<&u8>) -> isize {
+    match byte {
+        None => 0,
+        Some(b'~') => -1,
+        Some(byte) if byte.is_ascii_digit() => 0,
+        Some(byte) if byte.is_ascii_alphabetic() => *byte as isize,
+        Some(byte) => *byte as isize + 256,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::{Dialect, Error, PartType, Version};
+
+    #[test]
+    fn should_parse_upstream_version() {
+        let version = Version::parse("1.2.3", Dialect::Debian).unwrap();
+
+        assert_eq!(version.core(), (1, 2, 3));
+        assert_eq!(version.to_string(), "1.2.3");
+    }
+
+    #[test]
+    fn should_parse_epoch_and_revision() {
+        let version = Version::parse("2:1.0~rc1-3ubuntu1", Dialect::Debian).unwrap();
+
+        assert_eq!(version.core(), (1, 0, 0));
+        assert_eq!(version.to_string(), "2:1.0~rc1-3ubuntu1");
+    }
+
+    #[test]
+    fn should_keep_hyphens_in_upstream_version() {
+        let version = Version::parse("1.0-beta-2", Dialect::Debian).unwrap();
+
+        assert_eq!(version.to_string(), "1.0-beta-2");
+        assert!(version < Version::parse("1.0-beta-10", Dialect::Debian).unwrap());
+    }
+
+    #[test]
+    fn should_order_tilde_before_release_and_revision() {
+        let rc = Version::parse("1.0~rc1", Dialect::Debian).unwrap();
+        let release = Version::parse("1.0", Dialect::Debian).unwrap();
+        let revision = Version::parse("1.0-1", Dialect::Debian).unwrap();
+
+        assert!(rc < release);
+        assert!(release < revision);
+        assert!(rc < revision);
+    }
+
+    #[test]
+    fn should_order_by_epoch_first() {
+        let epoch_2 = Version::parse("2:1.0", Dialect::Debian).unwrap();
+        let epoch_1 = Version::parse("1:9.9", Dialect::Debian).unwrap();
+
+        assert!(epoch_2 > epoch_1);
+        assert!(Version::parse("9.9", Dialect::Debian).unwrap() < epoch_1);
+    }
+
+    #[test]
+    fn should_order_following_dpkg() {
+        let versions = [
+            "1.0~~", "1.0~~a", "1.0~", "1.0", "1.0a", "1.0+b1", "1.0.1", "1.0.10", "1.1",
+        ];
+
+        for pair in versions.windows(2) {
+            let a = Version::parse(pair[0], Dialect::Debian).unwrap();
+            let b = Version::parse(pair[1], Dialect::Debian).unwrap();
+
+            assert!(a < b, "{} < {}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn should_treat_equivalent_versions_as_equal() {
+        assert_eq!(
+            Version::parse("0:1.01", Dialect::Debian).unwrap(),
+            Version::parse("1.1", Dialect::Debian).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_compare_bumped_versions() {
+        let version = Version::parse("1:1.2~rc1-1", Dialect::Debian).unwrap();
+        let bumped = version.bump_minor();
+
+        assert_eq!(bumped.to_string(), "1:1.3.0");
+        assert!(bumped > version);
+
+        for version in [
+            "1:1.2~rc1-1",
+            "1:10rc",
+            "2:0.9-3",
+            "1.0~beta",
+            "1a",
+            "1.2.3.4",
+        ] {
+            let version = Version::parse(version, Dialect::Debian).unwrap();
+
+            for bumped in [
+                version.bump_major(),
+                version.bump_minor(),
+                version.bump_patch(),
+            ] {
+                assert!(bumped > version, "{} > {}", bumped, version);
+            }
+        }
+    }
+
+    #[test]
+    fn should_fail_invalid_versions() {
+        for (version, error) in [
+            ("", Error::EmptyPart(PartType::Major)),
+            (":1.0", Error::EmptyPart(PartType::Major)),
+            (
+                "a:1.0",
+                Error::InvalidCharacter {
+                    part: PartType::Major,
+                    byte: b'a',
+                    position: 0,
+                },
+            ),
+            (
+                "1:a1.0",
+                Error::InvalidCharacter {
+                    part: PartType::Major,
+                    byte: b'a',
+                    position: 2,
+                },
+            ),
+            (
+                "1.0_1",
+                Error::InvalidCharacter {
+                    part: PartType::Major,
+                    byte: b'_',
+                    position: 3,
+                },
+            ),
+            ("1.0-", Error::EmptyPart(PartType::BuildMetadata)),
+            (
+                "1.0-1_2",
+                Error::InvalidCharacter {
+                    part: PartType::BuildMetadata,
+                    byte: b'_',
+                    position: 5,
+                },
+            ),
+        ] {
+            assert_eq!(
+                Version::parse(version, Dialect::Debian),
+                Err(error),
+                "{}",
+                version
+            );
+        }
+    }
+}
@@ -0,0 +1,388 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::component::PartType;
+use crate::dialect::cursor::Cursor;
+use crate::dialect::{Dialect, DialectParser, Extension};
+use crate::error::Error;
+use crate::Version;
+
+pub struct DockerTag;
+
+/// The parts of a Docker image tag which can't be represented by the standard version parts.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Release {
+    /// A tag beginning with a version (like `1.21-alpine`), recording how many of the numeric parts
+    /// were specified, and the opaque suffix which follows them.
+    Versioned {
+        specified_parts: usize,
+        suffix: String,
+    },
+    /// A tag without a version (like `latest`), which sorts above every versioned tag.
+    Named(String),
+}
+
+/// A borrowed view of a tag, used for comparing and formatting.
+enum Tag<'a> {
+    Versioned {
+        specified_parts: usize,
+        suffix: &'a str,
+    },
+    Named(&'a str),
+}
+
+impl DialectParser for DockerTag {
+    const PARSES_WHOLE_STRING: bool = true;
+
+    /// Parse a Docker image tag, which may not follow SemVer.
+    ///
+    /// Tags beginning with a version (optionally with a `v` prefix) have up to three dot-separated
+    /// numeric parts, and the remainder of the tag (like `-alpine`) is kept as an opaque suffix. Tags
+    /// which don't begin with a version (like `latest`) are kept as a name, with every numeric part as
+    /// zero.
+    ///
+    /// Tags may only contain ASCII alphanumerics, underscores, dots and hyphens, and may not begin with
+    /// a dot or hyphen. Invalid characters in the suffix are reported against the prerelease part.
+    fn parse(version: &str, dialect: Dialect) -> Result<Version, Error> {
+        let mut cursor = Cursor {
+            bytes: version.as_bytes(),
+            position: 0,
+            leading_whitespace: 0,
+        };
+
+        let (prefix, remaining) = DockerTag::parse_prefix(version.as_bytes());
+        cursor.position = version.len() - remaining.len();
+
+        if !cursor.peek().is_some_and(|byte| byte.is_ascii_digit()) {
+            return DockerTag::parse_named(version, dialect);
+        }
+
+        let mut parts = vec![cursor.required_number(PartType::Major)?];
+
+        while parts.len() < 3
+            && cursor.peek() == Some(b'.')
+            && cursor.peek_at(1).is_some_and(|byte| byte.is_ascii_digit())
+        {
+            cursor.position += 1;
+            parts.push(cursor.required_number(match parts.len() {
+                1 => PartType::Minor,
+                _ => PartType::Patch,
+            })?);
+        }
+
+        let suffix = &version[cursor.offset()..];
+        DockerTag::validate_characters(suffix, cursor.offset(), PartType::Prerelease)?;
+
+        let mut version = Version::new(
+            parts[0],
+            parts.get(1).copied().unwrap_or(0),
+            parts.get(2).copied().unwrap_or(0),
+            None,
+            None,
+            prefix,
+            dialect,
+        );
+        version.extension = Some(Extension::DockerTag(Release::Versioned {
+            specified_parts: parts.len(),
+            suffix: suffix.to_string(),
+        }));
+
+        Ok(version)
+    }
+
+    /// Strip a leading `v` from the tag, but only when it's followed by the major version (so the
+    /// `v` in `vendor` is part of a named tag).
+    fn parse_prefix(version_bytes: &[u8]) -> (Option<char>, &[u8]) {
+        match version_bytes {
+            [b'v', digit, ..] if digit.is_ascii_digit() => (Some('v'), &version_bytes[1..]),
+            _ => (None, version_bytes),
+        }
+    }
+
+    /// Compare two tags by their version, using the suffix as a lexical tie-break.
+    ///
+    /// A tag without a suffix sorts before one with a suffix, and named tags sort above every
+    /// versioned tag (ordered lexically between themselves).
+    ///
+    /// For example: `1.20` < `1.21` < `1.21-alpine` < `1.21-bookworm` < `2` < `edge` < `latest`
+    fn cmp(a: &Version, b: &Version) -> Ordering {
+        match (tag(a), tag(b)) {
+            (Tag::Named(a), Tag::Named(b)) => a.cmp(b),
+            (Tag::Named(_), Tag::Versioned { .. }) => Ordering::Greater,
+            (Tag::Versioned { .. }, Tag::Named(_)) => Ordering::Less,
+            (
+                Tag::Versioned {
+                    suffix: a_suffix, ..
+                },
+                Tag::Versioned {
+                    suffix: b_suffix, ..
+                },
+            ) => a.core().cmp(&b.core()).then_with(|| a_suffix.cmp(b_suffix)),
+        }
+    }
+
+    /// Named tags (like `latest`) don't have a version, so can't be incremented or matched by a range.
+    fn is_versioned(version: &Version) -> bool {
+        !matches!(tag(version), Tag::Named(_))
+    }
+
+    /// Compare two tags and decide if they're considered equal, including their suffix.
+    fn eq(a: &Version, b: &Version) -> bool {
+        DockerTag::cmp(a, b) == Ordering::Equal
+    }
+
+    /// Normalize a tag into its major, minor and patch versions followed by the suffix, or the name of
+    /// a named tag.
+    fn canonicalize(version: &Version) -> String {
+        match tag(version) {
+            Tag::Named(name) => name.to_string(),
+            Tag::Versioned { suffix, .. } => format!(
+                "{}.{}.{}{}",
+                version.major, version.minor, version.patch, suffix
+            ),
+        }
+    }
+
+    /// Format a tag back into its original form, including only the numeric parts which were specified.
+    fn format(version: &Version) -> String {
+        match tag(version) {
+            Tag::Named(name) => name.to_string(),
+            Tag::Versioned {
+                specified_parts,
+                suffix,
+            } => {
+                let parts = [version.major, version.minor, version.patch];
+
+                format!(
+                    "{}{}{}",
+                    version.prefix.map(String::from).unwrap_or_default(),
+                    parts[..specified_parts]
+                        .iter()
+                        .map(|part| part.to_string())
+                        .collect::<Vec<_>>()
+                        .join("."),
+                    suffix
+                )
+            }
+        }
+    }
+}
+
+impl DockerTag {
+    /// Parse a tag which doesn't begin with a version (like `latest`).
+    fn parse_named(version: &str, dialect: Dialect) -> Result<Version, Error> {
+        if version.is_empty() {
+            return Err(Error::EmptyPart(PartType::Major));
+        }
+
+        if let byte @ (b'.' | b'-') = version.as_bytes()[0] {
+            return Err(Error::InvalidCharacter {
+                part: PartType::Major,
+                byte,
+                position: 0,
+            });
+        }
+
+        DockerTag::validate_characters(version, 0, PartType::Major)?;
+
+        let mut parsed = Version::new(0, 0, 0, None, None, None, dialect);
+        parsed.extension = Some(Extension::DockerTag(Release::Named(version.to_string())));
+
+        Ok(parsed)
+    }
+
+    /// Check every character in part of a tag is allowed, reporting errors at their position in the
+    /// full tag.
+    fn validate_characters(characters: &str, offset: usize, part: PartType) -> Result<(), Error> {
+        match characters
+            .bytes()
+            .position(|byte| !byte.is_ascii_alphanumeric() && !matches!(byte, b'_' | b'.' | b'-'))
+        {
+            Some(position) => Err(Error::InvalidCharacter {
+                part,
+                byte: characters.as_bytes()[position],
+                position: offset + position,
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Interpret the tag of a version.
+///
+/// Versions which weren't parsed using the dialect (for example, when bumping a version) are
+/// interpreted as a full version without a suffix.
+fn tag(version: &Version) -> Tag<'_> {
+    match &version.extension {
+        Some(Extension::DockerTag(Release::Named(name))) => Tag::Named(name),
+        Some(Extension::DockerTag(Release::Versioned {
+            specified_parts,
+            suffix,
+        })) => Tag::Versioned {
+            specified_parts: *specified_parts,
+            suffix,
+        },
+        _ => Tag::Versioned {
+            specified_parts: 3,
+            suffix: "",
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::{Dialect, Error, PartType, Requirement, Version};
+
+    #[test]
+    fn should_parse_version_prefix_and_suffix() {
+        let version = Version::parse("1.21-alpine", Dialect::DockerTag).unwrap();
+
+        assert_eq!(version.core(), (1, 21, 0));
+        assert!(!version.is_prerelease());
+        assert_eq!(version.to_string(), "1.21-alpine");
+    }
+
+    #[test]
+    fn should_preserve_original_tag() {
+        for tag in [
+            "1",
+            "1.21",
+            "v1.2.3",
+            "1.2.3.4",
+            "2023-01-01",
+            "3.12.0rc1-slim_bookworm",
+            "latest",
+            "_internal",
+        ] {
+            assert_eq!(
+                Version::parse(tag, Dialect::DockerTag).unwrap().to_string(),
+                tag
+            );
+        }
+    }
+
+    #[test]
+    fn should_order_named_tags_highest() {
+        let tags = [
+            "1.20",
+            "1.21",
+            "1.21-alpine",
+            "1.21-bookworm",
+            "2",
+            "v2.0.1",
+            "edge",
+            "latest",
+        ];
+
+        for pair in tags.windows(2) {
+            let a = Version::parse(pair[0], Dialect::DockerTag).unwrap();
+            let b = Version::parse(pair[1], Dialect::DockerTag).unwrap();
+
+            assert!(a < b, "{} < {}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn should_treat_unspecified_parts_as_zero() {
+        assert_eq!(
+            Version::parse("1.21", Dialect::DockerTag).unwrap(),
+            Version::parse("1.21.0", Dialect::DockerTag).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_fail_invalid_tags() {
+        for (tag, error) in [
+            ("", Error::EmptyPart(PartType::Major)),
+            (
+                "-latest",
+                Error::InvalidCharacter {
+                    part: PartType::Major,
+                    byte: b'-',
+                    position: 0,
+                },
+            ),
+            (
+                "lat est",
+                Error::InvalidCharacter {
+                    part: PartType::Major,
+                    byte: b' ',
+                    position: 3,
+                },
+            ),
+            (
+                "1.21-alp!ne",
+                Error::InvalidCharacter {
+                    part: PartType::Prerelease,
+                    byte: b'!',
+                    position: 8,
+                },
+            ),
+            (
+                "1.21+build",
+                Error::InvalidCharacter {
+                    part: PartType::Prerelease,
+                    byte: b'+',
+                    position: 4,
+                },
+            ),
+        ] {
+            assert_eq!(
+                Version::parse(tag, Dialect::DockerTag),
+                Err(error),
+                "{}",
+                tag
+            );
+        }
+    }
+
+    #[test]
+    fn should_drop_suffix_when_bumped() {
+        let version = Version::parse("1.21-alpine", Dialect::DockerTag).unwrap();
+
+        assert_eq!(version.bump_minor().to_string(), "1.22.0");
+    }
+
+    #[test]
+    fn should_fail_to_bump_named_tags() {
+        let version = Version::parse("latest", Dialect::DockerTag).unwrap();
+
+        assert_eq!(
+            version.bump_major_checked(),
+            Err(Error::MissingPart(PartType::Major))
+        );
+        assert_eq!(
+            version.bump_minor_checked(),
+            Err(Error::MissingPart(PartType::Major))
+        );
+        assert_eq!(
+            version.bump_patch_checked(),
+            Err(Error::MissingPart(PartType::Major))
+        );
+    }
+
+    #[test]
+    fn should_only_match_named_tags_exactly() {
+        let latest = Version::parse("latest", Dialect::DockerTag).unwrap();
+
+        for requirement in [">=1.0", ">1.0, <3.0", "<=latest", ">=edge"] {
+            assert!(
+                !Requirement::parse(requirement, Dialect::DockerTag)
+                    .unwrap()
+                    .matches(&latest),
+                "{}",
+                requirement
+            );
+        }
+
+        assert!(Requirement::parse("=latest", Dialect::DockerTag)
+            .unwrap()
+            .matches(&latest));
+        assert!(Requirement::parse("^latest", Dialect::DockerTag).is_err());
+    }
+}
@@ -0,0 +1,232 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::component::PartType;
+use crate::dialect::{Dialect, DialectParser};
+use crate::error::Error;
+use crate::{BuildMetadata, Version};
+
+pub struct Go;
+
+impl DialectParser for Go {
+    /// Parse a version string following the Go modules conventions.
+    ///
+    /// Versions must begin with a `v` prefix, and the remainder of the version follows the standard
+    /// dialect. The `+incompatible` build metadata is only allowed on major versions v2 and above.
+    fn parse(version: &str, dialect: Dialect) -> Result<Version, Error> {
+        Go::validate_prefix(version.as_bytes())?;
+
+        Go::validate_incompatible(Version::parse_parts(version, dialect)?, version.len())
+    }
+
+    /// Parse raw bytes following the Go modules conventions, without first converting them into a
+    /// string.
+    fn parse_bytes(version: &[u8], dialect: Dialect) -> Result<Version, Error> {
+        Go::validate_prefix(version)?;
+
+        Go::validate_incompatible(Version::parse_parts_bytes(version, dialect)?, version.len())
+    }
+
+    /// Parse a version string following the Go modules conventions, collecting every error rather than
+    /// returning the first.
+    fn parse_collecting(version: &str, dialect: Dialect) -> Result<Version, Vec<Error>> {
+        match Go::validate_prefix(version.as_bytes()) {
+            Ok(()) => Version::parse_parts_collecting(version, dialect).and_then(|parsed| {
+                Go::validate_incompatible(parsed, version.len()).map_err(|error| vec![error])
+            }),
+            Err(error) => {
+                let mut errors = vec![error];
+
+                if let Err(remaining_errors) = Version::parse_parts_collecting(version, dialect) {
+                    errors.extend(remaining_errors);
+                }
+
+                Err(errors)
+            }
+        }
+    }
+
+    /// Validate a version string following the Go modules conventions.
+    fn validate(version: &str, dialect: Dialect) -> Result<(), Error> {
+        Go::validate_prefix(version.as_bytes())?;
+        Version::validate_parts(version, dialect)?;
+
+        if version.ends_with("+incompatible") {
+            // Only an incompatible version needs to be parsed to check its major version
+            Go::parse(version, dialect)?;
+        }
+
+        Ok(())
+    }
+
+    /// Strip the leading `v` from the version string.
+    fn parse_prefix(version_bytes: &[u8]) -> (Option<char>, &[u8]) {
+        match version_bytes.first() {
+            Some(b'v') => (Some('v'), &version_bytes[1..]),
+            _ => (None, version_bytes),
+        }
+    }
+}
+
+impl Go {
+    /// Whether the version is marked with the `+incompatible` build metadata, which Go modules use for
+    /// major versions (v2 and above) published without a `go.mod` file.
+    pub(crate) fn is_incompatible(version: &Version) -> bool {
+        version.major >= 2
+            && matches!(&version.build_metadata, BuildMetadata::Identifier(identifier) if identifier == "incompatible")
+    }
+
+    /// Ensure the `+incompatible` build metadata is only used on major versions v2 and above, as
+    /// earlier major versions never need a `go.mod` file to be compatible.
+    fn validate_incompatible(version: Version, length: usize) -> Result<Version, Error> {
+        match &version.build_metadata {
+            BuildMetadata::Identifier(identifier)
+                if identifier == "incompatible" && version.major < 2 =>
+            {
+                Err(Error::InvalidCharacter {
+                    part: PartType::BuildMetadata,
+                    byte: b'i',
+                    position: length - identifier.len(),
+                })
+            }
+            _ => Ok(version),
+        }
+    }
+
+    /// Ensure the version string begins with the mandatory `v` prefix.
+    fn validate_prefix(version: &[u8]) -> Result<(), Error> {
+        match version.first() {
+            Some(b'v') | None => Ok(()),
+            Some(&byte) => Err(Error::InvalidCharacter {
+                part: PartType::Major,
+                byte,
+                position: 0,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    use crate::{BuildMetadata, Dialect, Error, PartType, Version};
+
+    #[test]
+    fn should_parse_prefixed_version() {
+        let version = Version::parse("v1.2.3", Dialect::Go).unwrap();
+
+        assert_eq!(version.major, 1);
+        assert_eq!(version.minor, 2);
+        assert_eq!(version.patch, 3);
+        assert!(!version.is_incompatible());
+        assert_eq!(version.to_string(), "v1.2.3");
+    }
+
+    #[test]
+    fn should_parse_incompatible_version() {
+        let version = Version::parse("v2.0.0+incompatible", Dialect::Go).unwrap();
+
+        assert_eq!(version.major, 2);
+        assert_eq!(
+            version.build_metadata,
+            BuildMetadata::Identifier("incompatible".to_string())
+        );
+        assert!(version.is_incompatible());
+        assert_eq!(version.to_string(), "v2.0.0+incompatible");
+    }
+
+    #[test]
+    fn should_ignore_incompatible_when_comparing() {
+        let incompatible = Version::parse("v2.0.0+incompatible", Dialect::Go).unwrap();
+        let compatible = Version::parse("v2.0.0", Dialect::Go).unwrap();
+        let next = Version::parse("v2.0.1", Dialect::Go).unwrap();
+
+        assert_eq!(incompatible, compatible);
+        assert!(incompatible < next);
+        assert!(Version::parse("v2.0.0-rc.1", Dialect::Go).unwrap() < incompatible);
+    }
+
+    #[test]
+    fn should_fail_incompatible_before_v2() {
+        for version in ["v0.1.0+incompatible", "v1.2.3+incompatible"] {
+            let error = Err(Error::InvalidCharacter {
+                part: PartType::BuildMetadata,
+                byte: b'i',
+                position: 7,
+            });
+
+            assert_eq!(Version::parse(version, Dialect::Go), error, "{}", version);
+            assert_eq!(
+                Version::parse_bytes(version.as_bytes(), Dialect::Go),
+                error,
+                "{}",
+                version
+            );
+            assert_eq!(
+                Version::validate(version, Dialect::Go),
+                error.map(|_: Version| ()),
+                "{}",
+                version
+            );
+        }
+
+        let version = Version::parse("v1.2.3", Dialect::Go)
+            .unwrap()
+            .with_build_metadata("incompatible")
+            .unwrap();
+
+        assert!(!version.is_incompatible());
+    }
+
+    #[test]
+    fn should_fail_missing_prefix() {
+        let version = Version::parse("1.2.3", Dialect::Go);
+
+        assert_eq!(
+            version,
+            Err(Error::InvalidCharacter {
+                part: PartType::Major,
+                byte: b'1',
+                position: 0
+            })
+        );
+        assert_eq!(Version::validate("1.2.3", Dialect::Go), version.map(|_| ()));
+    }
+
+    #[test]
+    fn should_collect_missing_prefix_with_other_errors() {
+        let errors = Version::parse_collecting("1.2.x", Dialect::Go).unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![
+                Error::InvalidCharacter {
+                    part: PartType::Major,
+                    byte: b'1',
+                    position: 0
+                },
+                Error::InvalidCharacter {
+                    part: PartType::Patch,
+                    byte: b'x',
+                    position: 4
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn should_fail_uppercase_prefix() {
+        let version = Version::parse("V1.2.3", Dialect::Go);
+
+        assert_eq!(
+            version,
+            Err(Error::InvalidCharacter {
+                part: PartType::Major,
+                byte: b'V',
+                position: 0
+            })
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use crate::dialect::DialectParser;
+
+pub struct Hex;
+
+impl DialectParser for Hex {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::{Dialect, Error, PartType, Version};
+
+    #[test]
+    fn should_parse_standard_versions() {
+        let version = Version::parse("1.2.3-rc.1+build.5", Dialect::Hex).unwrap();
+
+        assert_eq!(version.core(), (1, 2, 3));
+        assert!(version.is_prerelease());
+        assert_eq!(version.to_string(), "1.2.3-rc.1+build.5");
+    }
+
+    #[test]
+    fn should_ignore_build_metadata_when_comparing() {
+        assert_eq!(
+            Version::parse("1.2.3+build.1", Dialect::Hex).unwrap(),
+            Version::parse("1.2.3+build.2", Dialect::Hex).unwrap()
+        );
+        assert!(
+            Version::parse("1.2.3-rc.1", Dialect::Hex).unwrap()
+                < Version::parse("1.2.3", Dialect::Hex).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_fail_invalid_versions() {
+        assert_eq!(
+            Version::parse("v1.2.3", Dialect::Hex),
+            Err(Error::InvalidCharacter {
+                part: PartType::Major,
+                byte: b'v',
+                position: 0
+            })
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use crate::component::PartType;
+use crate::dialect::{
+    CapturedBytes, DialectParser, NextPartType, RemainingUnparsedBytes, Standard,
+};
+use crate::error::Error;
+
+pub struct Lenient;
+
+impl DialectParser for Lenient {
+    /// Validate a single byte following the standard dialect, but permit leading zeros in the major,
+    /// minor and patch versions.
+    ///
+    /// The leading zeros are dropped when the parts are converted into numbers, so `1.01.0` is
+    /// normalized to `1.1.0`.
+    fn parse_byte(
+        byte: &u8,
+        position: usize,
+        part: (PartType, &CapturedBytes),
+        remaining_bytes: &RemainingUnparsedBytes,
+    ) -> Result<NextPartType, Error> {
+        match Standard::parse_byte(byte, position, part, remaining_bytes) {
+            Err(Error::InvalidPrecedingZero(
+                PartType::Major | PartType::Minor | PartType::Patch,
+            )) => Ok(None),
+            result => result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::{Dialect, Error, PartType, Version};
+
+    #[test]
+    fn should_normalize_leading_zeros() {
+        let version = Version::parse("01.02.0003", Dialect::Lenient).unwrap();
+
+        assert_eq!(version.core(), (1, 2, 3));
+        assert_eq!(version.to_string(), "1.2.3");
+        assert_eq!(
+            Version::parse("1.01.0", Dialect::Lenient)
+                .unwrap()
+                .to_string(),
+            "1.1.0"
+        );
+    }
+
+    #[test]
+    fn should_still_fail_leading_zeros_in_standard_dialect() {
+        assert_eq!(
+            Version::parse("1.01.0", Dialect::Standard),
+            Err(Error::InvalidPrecedingZero(PartType::Minor))
+        );
+    }
+
+    #[test]
+    fn should_otherwise_follow_standard_dialect() {
+        let version = Version::parse("1.00.0-alpha.1+build", Dialect::Lenient).unwrap();
+
+        assert_eq!(version.to_string(), "1.0.0-alpha.1+build");
+        assert!(version < Version::parse("1.0.0", Dialect::Lenient).unwrap());
+        assert_eq!(
+            Version::parse("1.0x.0", Dialect::Lenient),
+            Err(Error::InvalidCharacter {
+                part: PartType::Minor,
+                byte: b'x',
+                position: 3
+            })
+        );
+    }
+}
@@ -1,17 +1,43 @@
 use alloc::format;
-use alloc::string::String;
+use alloc::string::{String, ToString};
+use alloc::vec;
 use alloc::vec::Vec;
 use core::cmp::Ordering;
+use core::fmt::Write;
+use core::hash::{Hash, Hasher};
 
+pub(crate) use cargo::Cargo;
+pub(crate) use composer::Composer;
+pub(crate) use debian::Debian;
+pub(crate) use docker_tag::DockerTag;
+pub(crate) use go::Go;
+pub(crate) use hex::Hex;
+pub(crate) use lenient::Lenient;
+pub(crate) use npm::Npm;
+pub(crate) use nuget::NuGet;
+pub(crate) use pep440::Pep440;
+pub(crate) use rubygems::RubyGems;
 pub(crate) use standard::Standard;
 
 use crate::component::PartType;
 use crate::error::Error;
 use crate::{BuildMetadata, Prerelease, Version};
 
+mod cargo;
+mod composer;
+mod cursor;
+mod debian;
+mod docker_tag;
+mod go;
+mod hex;
+mod lenient;
+mod npm;
+mod nuget;
+mod pep440;
+mod rubygems;
 mod standard;
 
-pub(crate) type CapturedBytes = Vec<u8>;
+pub(crate) type CapturedBytes = [u8];
 pub(crate) type RemainingUnparsedBytes = [u8];
 pub(crate) type NextPartType = Option<PartType>;
 
@@ -23,15 +49,345 @@ pub(crate) type NextPartType = Option<PartType>;
 /// Every version has to be parsed following a particular dialect - likely standard SemVer. However,
 /// dialects open up support for version comparisons following particular behaviour outlined by
 /// Cargo, or wider support for other languages like Composer (for PHP), npm (for JavaScript), etc.
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Dialect {
     /// The standard dialect follows the [Semver Versioning 2.0.0](https://github.com/semver/semver/blob/master/semver.md#semantic-versioning-200) specification
     Standard,
+
+    /// The Cargo dialect follows the ordering used by [Cargo](https://doc.rust-lang.org/cargo/reference/resolver.html) when
+    /// resolving dependencies. Parsing and precedence (including the ordering of prereleases) are identical to the
+    /// standard dialect, and the only difference is that build metadata is used to break ties between versions of equal
+    /// precedence. Cargo's resolver orders a prerelease below its release (like `1.2.3-alpha` < `1.2.3`) exactly like
+    /// SemVer, and only treats prereleases differently when matching requirements, so the two dialects deliberately
+    /// agree when ordering them.
+    Cargo,
+
+    /// The npm dialect is the standard dialect with a single optional leading `v` or `V` prefix, as commonly
+    /// emitted by [npm](https://docs.npmjs.com/about-semantic-versioning) and Git tags (like `v1.2.3`). The prefix
+    /// is preserved when formatting, and otherwise versions are parsed, compared and matched exactly like the
+    /// standard dialect.
+    Npm,
+
+    /// The PEP 440 dialect follows the [Python version specification](https://peps.python.org/pep-0440/), used
+    /// by Python packaging. This supports epochs (like `2!1.0`), any number of release segments, pre, post and
+    /// development releases (like `1.0a1`, `1.0.post1` and `1.0.dev1`) and local versions (like `1.0+ubuntu.1`).
+    Pep440,
+
+    /// The Go dialect follows the conventions used by [Go modules](https://go.dev/ref/mod#versions), where
+    /// versions must begin with a `v` prefix (like `v1.2.3`). The `+incompatible` build metadata is recognised on
+    /// major versions v2 and above (and rejected below), and (like all build metadata) is ignored when comparing
+    /// versions.
+    Go,
+
+    /// The Debian dialect follows the [Debian version format](https://www.debian.org/doc/debian-policy/ch-controlfields.html#version)
+    /// (`[epoch:]upstream[-revision]`), used by Debian packaging. Versions are compared following the algorithm
+    /// used by `dpkg`, where `~` sorts before everything (like `1.0~rc1` < `1.0` < `1.0-1`).
+    Debian,
+
+    /// The RubyGems dialect follows the [RubyGems version format](https://guides.rubygems.org/patterns/#semantic-versioning),
+    /// used by Ruby packaging. Versions can have any number of dot-separated segments (like `1.2.3.4.5`), and
+    /// any segment containing a letter marks the version as a prerelease (like `1.0.0.pre` < `1.0.0`).
+    RubyGems,
+
+    /// The NuGet dialect follows the [NuGet version format](https://learn.microsoft.com/en-us/nuget/concepts/package-versioning),
+    /// used by .NET packaging. Versions follow the standard dialect, but may include a fourth numeric part (the revision)
+    /// which is compared after the patch version (like `1.2.3` < `1.2.3.4` < `1.2.4`). Release labels are compared
+    /// case-insensitively (like `1.0.0-Beta` == `1.0.0-beta`).
+    NuGet,
+
+    /// The lenient dialect follows the standard dialect, but accepts leading zeros in the major, minor and patch
+    /// versions, as emitted by some real-world tooling (like `1.01.0`). Leading zeros are normalized away (so
+    /// `1.01.0` is parsed as `1.1.0`).
+    Lenient,
+
+    /// The Composer dialect follows the [Composer version format](https://getcomposer.org/doc/articles/versions.md),
+    /// used by PHP packaging. Versions may have a `v` prefix, between one and four numeric segments, and a stability
+    /// suffix, which are ordered by stability (like `1.0.0-dev` < `1.0.0-alpha` < `1.0.0-beta` < `1.0.0-RC` < `1.0.0`
+    /// < `1.0.0-patch1`). A stability flag (like `1.0.0@beta`) is kept, but doesn't affect the ordering.
+    Composer,
+
+    /// The Docker tag dialect parses [container image tags](https://docs.docker.com/reference/cli/docker/image/tag/),
+    /// which often don't follow SemVer. The leading version (like `1.21` in `1.21-alpine`) is parsed, and the rest of the
+    /// tag is kept as an opaque suffix, used as a lexical tie-break. Tags without a version (like `latest`) sort above
+    /// every versioned tag, can't be bumped, and only match a requirement exactly (like `=latest`).
+    DockerTag,
+
+    /// The Hex dialect follows the conventions used by [Hex](https://hexdocs.pm/elixir/Version.html), the package
+    /// manager for Elixir and Erlang. Versions follow the standard dialect, and requirements support the pessimistic
+    /// `~>` operator (like `~> 2.1.2`, meaning `>=2.1.2, <2.2.0`, and `~> 2.1`, meaning `>=2.1.0, <3.0.0`).
+    Hex,
+}
+
+/// Call a [`DialectParser`] method on the parser which implements a dialect.
+///
+/// This is the only place each dialect is mapped to its parser, so adding a dialect only requires a new
+/// arm here.
+macro_rules! dispatch {
+    ($dialect:expr, $parser:ident => $call:expr) => {
+        match $dialect {
+            Dialect::Standard => {
+                type $parser = Standard;
+                $call
+            }
+            Dialect::Cargo => {
+                type $parser = Cargo;
+                $call
+            }
+            Dialect::Npm => {
+                type $parser = Npm;
+                $call
+            }
+            Dialect::Pep440 => {
+                type $parser = Pep440;
+                $call
+            }
+            Dialect::Go => {
+                type $parser = Go;
+                $call
+            }
+            Dialect::Debian => {
+                type $parser = Debian;
+                $call
+            }
+            Dialect::RubyGems => {
+                type $parser = RubyGems;
+                $call
+            }
+            Dialect::NuGet => {
+                type $parser = NuGet;
+                $call
+            }
+            Dialect::Lenient => {
+                type $parser = Lenient;
+                $call
+            }
+            Dialect::Composer => {
+                type $parser = Composer;
+                $call
+            }
+            Dialect::DockerTag => {
+                type $parser = DockerTag;
+                $call
+            }
+            Dialect::Hex => {
+                type $parser = Hex;
+                $call
+            }
+        }
+    };
+}
+
+impl Dialect {
+    /// Parse a string into a Version instance, following the dialect.
+    ///
+    /// This is the same as [`Version::parse`].
+    ///
+    /// ```
+    /// use smvr::Dialect;
+    /// let version = Dialect::Cargo.parse("1.2.3-beta")?;
+    /// assert_eq!(version.dialect(), Dialect::Cargo);
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn parse(self, version: &str) -> Result<Version, Error> {
+        dispatch!(self, Parser => Parser::parse(version, self))
+    }
+
+    /// Whether a requirement without an operator (like `1.2.3`) is a caret requirement (like `^1.2.3`),
+    /// rather than an exact match (like `=1.2.3`).
+    ///
+    /// An explicit `=` operator is always an exact match.
+    ///
+    /// ```
+    /// use smvr::Dialect;
+    /// assert!(Dialect::Cargo.is_bare_requirement_caret());
+    /// assert!(!Dialect::Npm.is_bare_requirement_caret());
+    /// ```
+    pub fn is_bare_requirement_caret(&self) -> bool {
+        dispatch!(self, Parser => Parser::is_bare_requirement_caret())
+    }
+
+    /// Parse the raw bytes of a version string, following the dialect.
+    pub(crate) fn parse_bytes(self, version: &[u8]) -> Result<Version, Error> {
+        dispatch!(self, Parser => Parser::parse_bytes(version, self))
+    }
+
+    /// Parse a version string following the dialect, collecting every error rather than returning the
+    /// first.
+    pub(crate) fn parse_collecting(self, version: &str) -> Result<Version, Vec<Error>> {
+        dispatch!(self, Parser => Parser::parse_collecting(version, self))
+    }
+
+    /// Validate a version string following the dialect, without constructing a Version instance.
+    pub(crate) fn validate(self, version: &str) -> Result<(), Error> {
+        dispatch!(self, Parser => Parser::validate(version, self))
+    }
+
+    /// Strip any prefix permitted by the dialect from the start of a version string.
+    pub(crate) fn parse_prefix(self, version_bytes: &[u8]) -> (Option<char>, &[u8]) {
+        dispatch!(self, Parser => Parser::parse_prefix(version_bytes))
+    }
+
+    /// Validate a single byte of a version string following the dialect, and decide if it transitions
+    /// to a different part.
+    pub(crate) fn parse_byte(
+        self,
+        byte: &u8,
+        position: usize,
+        part: (PartType, &CapturedBytes),
+        remaining_bytes: &RemainingUnparsedBytes,
+    ) -> Result<NextPartType, Error> {
+        dispatch!(self, Parser => Parser::parse_byte(byte, position, part, remaining_bytes))
+    }
+
+    /// Compare two versions following the dialect's order.
+    pub(crate) fn compare(self, a: &Version, b: &Version) -> Ordering {
+        dispatch!(self, Parser => Parser::cmp(a, b))
+    }
+
+    /// Decide if two versions are considered equal, following the dialect.
+    pub(crate) fn equals(self, a: &Version, b: &Version) -> bool {
+        dispatch!(self, Parser => Parser::eq(a, b))
+    }
+
+    /// A normalized form of a version, which is the same for versions of equal precedence, following
+    /// the dialect.
+    pub(crate) fn canonicalize(self, version: &Version) -> String {
+        dispatch!(self, Parser => Parser::canonicalize(version))
+    }
+
+    /// Hash a version consistently with its equality, following the dialect.
+    pub(crate) fn hash_version<H: Hasher>(self, version: &Version, state: &mut H) {
+        dispatch!(self, Parser => Parser::hash(version, state))
+    }
+
+    /// The dialect-specific parts of a version which still apply once its numeric parts change,
+    /// following the dialect.
+    pub(crate) fn retain_extension(
+        self,
+        version: &Version,
+        changed: &Version,
+    ) -> Option<Extension> {
+        dispatch!(self, Parser => Parser::retain_extension(version, changed))
+    }
+
+    /// Whether a version has numeric parts, following the dialect.
+    pub(crate) fn is_versioned(self, version: &Version) -> bool {
+        dispatch!(self, Parser => Parser::is_versioned(version))
+    }
+
+    /// Write a version back into its human-readable form, following the dialect.
+    pub(crate) fn write_to<W: Write>(self, version: &Version, w: &mut W) -> core::fmt::Result {
+        dispatch!(self, Parser => Parser::write_to(version, w))
+    }
+
+    /// Skip any part of a version string which comes before the numeric parts (like the epoch in
+    /// `1!2.0`), following the dialect.
+    pub(crate) fn strip_epoch(self, version: &str) -> &str {
+        dispatch!(self, Parser => Parser::strip_epoch(version))
+    }
+
+    /// The most significant part which differs between the parts of two versions specific to the
+    /// dialect (like a NuGet revision), following the dialect.
+    pub(crate) fn diff(self, a: &Version, b: &Version) -> Option<PartType> {
+        dispatch!(self, Parser => Parser::diff(a, b))
+    }
+}
+
+/// Parts of a version which are specific to a particular dialect, and can't be represented by the
+/// standard major, minor, patch, prerelease and build metadata parts.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Extension {
+    Pep440(pep440::Release),
+    Debian(debian::Release),
+    RubyGems(rubygems::Release),
+    NuGet(nuget::Release),
+    Composer(composer::Release),
+    DockerTag(docker_tag::Release),
 }
 
 pub(crate) trait DialectParser {
+    /// Whether the dialect parses a version string as a whole, rather than one byte at a time.
+    ///
+    /// Dialects which parse a version string as a whole must implement [`DialectParser::parse`] and
+    /// [`DialectParser::format`], as every other default is built on them. Byte parsing converts the
+    /// bytes into a string first, collecting errors stops at the first error, and validating requires
+    /// parsing (and allocating) the full version.
+    const PARSES_WHOLE_STRING: bool = false;
+
+    /// Parse a version string into a Version instance.
+    ///
+    /// The default implementation parses the version string one byte at a time, using the
+    /// dialect's prefix and byte parsing rules.
+    fn parse(version: &str, dialect: Dialect) -> Result<Version, Error> {
+        Version::parse_parts(version, dialect)
+    }
+
+    /// Parse the raw bytes of a version string into a Version instance.
+    ///
+    /// The default implementation parses the bytes one at a time, using the dialect's prefix and
+    /// byte parsing rules, without first converting them into a string.
+    fn parse_bytes(version: &[u8], dialect: Dialect) -> Result<Version, Error> {
+        if Self::PARSES_WHOLE_STRING {
+            return Self::parse(Version::from_utf8(version)?, dialect);
+        }
+
+        Version::parse_parts_bytes(version, dialect)
+    }
+
+    /// Parse a version string into a Version instance, collecting every error rather than returning
+    /// the first.
+    ///
+    /// The default implementation parses the version string one byte at a time, continuing past any
+    /// invalid bytes or parts.
+    fn parse_collecting(version: &str, dialect: Dialect) -> Result<Version, Vec<Error>> {
+        if Self::PARSES_WHOLE_STRING {
+            return Self::parse(version, dialect).map_err(|error| vec![error]);
+        }
+
+        Version::parse_parts_collecting(version, dialect)
+    }
+
+    /// Validate a version string, without constructing a Version instance.
+    ///
+    /// The default implementation validates the version string one byte at a time, using the
+    /// dialect's prefix and byte parsing rules, without allocating.
+    fn validate(version: &str, dialect: Dialect) -> Result<(), Error> {
+        if Self::PARSES_WHOLE_STRING {
+            return Self::parse(version, dialect).map(|_| ());
+        }
+
+        Version::validate_parts(version, dialect)
+    }
+
+    /// Strip any dialect-specific prefix from the start of a version string, before the major part is parsed.
+    ///
+    /// The default implementation does not permit any prefix.
+    fn parse_prefix(version_bytes: &[u8]) -> (Option<char>, &[u8]) {
+        (None, version_bytes)
+    }
+
+    /// Skip any part of a version string which comes before the numeric parts (like an epoch).
+    ///
+    /// The default implementation has nothing before the numeric parts.
+    fn strip_epoch(version: &str) -> &str {
+        version
+    }
+
+    /// The most significant part which differs between the parts of two versions specific to the
+    /// dialect, reported as the standard part it's ordered alongside (like an epoch as the major
+    /// version).
+    ///
+    /// The default implementation has no parts specific to the dialect.
+    fn diff(_a: &Version, _b: &Version) -> Option<PartType> {
+        None
+    }
+
+    /// Validate a single byte of a version string, and decide if it transitions to a different part.
+    ///
+    /// The position is the byte offset from the start of the version string, used when reporting errors.
     fn parse_byte(
         byte: &u8,
+        position: usize,
         part: (PartType, &CapturedBytes),
         remaining_bytes: &RemainingUnparsedBytes,
     ) -> Result<NextPartType, Error> {
@@ -45,8 +401,7 @@ pub(crate) trait DialectParser {
 
         if byte == &b'.' {
             match part.0 {
-                PartType::Major => return Ok(Some(PartType::Minor)),
-                PartType::Minor => return Ok(Some(PartType::Patch)),
+                PartType::Major | PartType::Minor => return Ok(part.0.next()),
 
                 // The prerelease part is special, in that it doesn't have to transition to another
                 // part of the version when encountering a dot. Specifically, the dot represents a new
@@ -64,7 +419,11 @@ pub(crate) trait DialectParser {
             PartType::Major => {
                 if !(&b'0'..=&b'9').contains(&byte) {
                     // Major, minor and patch versions can only be digits
-                    return Err(Error::InvalidCharacter(part.0));
+                    return Err(Error::InvalidCharacter {
+                        part: part.0,
+                        byte: *byte,
+                        position,
+                    });
                 }
 
                 let is_first_digit = part.1.is_empty();
@@ -78,7 +437,11 @@ pub(crate) trait DialectParser {
             PartType::Minor => {
                 if !(&b'0'..=&b'9').contains(&byte) {
                     // Major, minor and patch versions can only be digits
-                    return Err(Error::InvalidCharacter(part.0));
+                    return Err(Error::InvalidCharacter {
+                        part: part.0,
+                        byte: *byte,
+                        position,
+                    });
                 }
 
                 let is_first_digit = part.1.is_empty();
@@ -92,7 +455,11 @@ pub(crate) trait DialectParser {
             PartType::Patch => {
                 if !(&b'0'..=&b'9').contains(&byte) {
                     // Major, minor and patch versions can only be digits
-                    return Err(Error::InvalidCharacter(part.0));
+                    return Err(Error::InvalidCharacter {
+                        part: part.0,
+                        byte: *byte,
+                        position,
+                    });
                 }
 
                 let is_first_digit = part.1.is_empty();
@@ -106,12 +473,20 @@ pub(crate) trait DialectParser {
             }
             PartType::Prerelease => {
                 if !byte.is_ascii_alphanumeric() && byte != &b'-' {
-                    return Err(Error::InvalidCharacter(part.0));
+                    return Err(Error::InvalidCharacter {
+                        part: part.0,
+                        byte: *byte,
+                        position,
+                    });
                 }
             }
             PartType::BuildMetadata => {
                 if !byte.is_ascii_alphanumeric() && byte != &b'-' && byte != &b'.' {
-                    return Err(Error::InvalidCharacter(part.0));
+                    return Err(Error::InvalidCharacter {
+                        part: part.0,
+                        byte: *byte,
+                        position,
+                    });
                 }
             }
         }
@@ -125,47 +500,11 @@ pub(crate) trait DialectParser {
     ///
     /// The default implementation follows the [Semantic Versioning 2.0.0 specification](https://github.com/semver/semver/blob/master/semver.md#semantic-versioning-specification-semver).
     fn cmp(a: &Version, b: &Version) -> Ordering {
-        if a.major != b.major {
-            return if a.major > b.major {
-                Ordering::Greater
-            } else {
-                Ordering::Less
-            };
-        }
-
-        if a.minor != b.minor {
-            return if a.minor > b.minor {
-                return Ordering::Greater;
-            } else {
-                Ordering::Less
-            };
-        }
-
-        if a.patch != b.patch {
-            return if a.patch > b.patch {
-                Ordering::Greater
-            } else {
-                Ordering::Less
-            };
-        }
-
-        if let Prerelease::Identifier(a) = &a.prerelease {
-            if let Prerelease::Identifier(b) = &b.prerelease {
-                return if a < b {
-                    Ordering::Less
-                } else if a.eq(b) {
-                    Ordering::Equal
-                } else {
-                    Ordering::Greater
-                };
-            }
-
-            return Ordering::Less;
-        } else if b.prerelease != Prerelease::Empty {
-            return Ordering::Greater;
-        }
-
-        Ordering::Equal
+        a.major
+            .cmp(&b.major)
+            .then_with(|| a.minor.cmp(&b.minor))
+            .then_with(|| a.patch.cmp(&b.patch))
+            .then_with(|| compare_prerelease(a, b))
     }
 
     /// Compare two versions and decide if they're considered equal, based on the dialect.
@@ -178,32 +517,116 @@ pub(crate) trait DialectParser {
             && a.prerelease.eq(&b.prerelease)
     }
 
-    /// Format a version back into a human-readable string.
+    /// The dialect-specific parts of a version which still apply once its numeric parts change (like
+    /// when it's bumped), such as an epoch.
     ///
-    /// The output of this should match the original un-parsed version passed in.
+    /// The changed version has the new numeric parts, but no dialect-specific parts.
     ///
-    /// The default implementation follows the [Semantic Versioning 2.0.0 specification](https://github.com/semver/semver/blob/master/semver.md#semantic-versioning-specification-semver).
-    fn format(version: &Version) -> String {
+    /// The default implementation has no dialect-specific parts to keep.
+    fn retain_extension(_version: &Version, _changed: &Version) -> Option<Extension> {
+        None
+    }
+
+    /// Whether a version has numeric parts, so that it can be incremented and matched by a range of
+    /// versions.
+    ///
+    /// The default implementation treats every version as having numeric parts.
+    fn is_versioned(_version: &Version) -> bool {
+        true
+    }
+
+    /// A normalized form of a version, which is the same for versions of equal precedence (ignoring
+    /// build metadata), and different for every other version.
+    ///
+    /// The default implementation follows `major.minor.patch[-prerelease]`, omitting any prefix.
+    fn canonicalize(version: &Version) -> String {
         let mut string = format!("{}.{}.{}", version.major, version.minor, version.patch);
 
+        for (i, component) in version.prerelease.components().iter().enumerate() {
+            string.push(if i == 0 { '-' } else { '.' });
+            string.push_str(&component.to_string());
+        }
+
+        string
+    }
+
+    /// Hash a version consistently with the dialect's equality, so that equal versions always have
+    /// equal hashes.
+    ///
+    /// The default implementation hashes the major, minor and patch versions and the prerelease
+    /// identifier, which is consistent with equality following the [Semantic Versioning 2.0.0 specification](https://github.com/semver/semver/blob/master/semver.md#semantic-versioning-specification-semver).
+    fn hash<H: Hasher>(version: &Version, state: &mut H) {
+        version.major.hash(state);
+        version.minor.hash(state);
+        version.patch.hash(state);
+        version.prerelease.hash(state);
+    }
+
+    /// Write a version back into its human-readable form, without allocating.
+    ///
+    /// The output of this should match the original un-parsed version passed in. Prerelease identifiers
+    /// and build metadata are case-sensitive, so are reproduced without any normalization.
+    ///
+    /// The default implementation follows the [Semantic Versioning 2.0.0 specification](https://github.com/semver/semver/blob/master/semver.md#semantic-versioning-specification-semver).
+    fn write_to<W: Write>(version: &Version, w: &mut W) -> core::fmt::Result {
+        if Self::PARSES_WHOLE_STRING {
+            return w.write_str(&Self::format(version));
+        }
+
+        if let Some(prefix) = version.prefix {
+            w.write_char(prefix)?;
+        }
+
+        write!(w, "{}.{}.{}", version.major, version.minor, version.patch)?;
+
         if let Prerelease::Identifier(identifier) = &version.prerelease {
-            string.push_str(&format!(
-                "-{}",
-                identifier
-                    .iter()
-                    .fold(String::new(), |mut str, part| {
-                        str.push_str(&format!(".{}", part));
-
-                        str
-                    })
-                    .trim_start_matches('.')
-            ));
+            w.write_char('-')?;
+
+            for (i, component) in identifier.iter().enumerate() {
+                if i > 0 {
+                    w.write_char('.')?;
+                }
+
+                write!(w, "{}", component)?;
+            }
         }
 
         if let BuildMetadata::Identifier(identifier) = &version.build_metadata {
-            string.push_str(&format!("+{}", identifier));
+            write!(w, "+{}", identifier)?;
         }
 
+        Ok(())
+    }
+
+    /// Whether a requirement without an operator (like `1.2.3`) is a caret requirement, rather than an
+    /// exact match.
+    ///
+    /// The default implementation treats a bare version as an exact match.
+    fn is_bare_requirement_caret() -> bool {
+        false
+    }
+
+    /// Format a version back into a human-readable string.
+    ///
+    /// The default implementation collects the output of [`DialectParser::write_to`].
+    fn format(version: &Version) -> String {
+        let mut string = String::new();
+
+        // Writing into a string never fails
+        let _ = Self::write_to(version, &mut string);
+
         string
     }
 }
+
+/// Order the prerelease identifiers of two versions, one identifier at a time.
+///
+/// A version without a prerelease identifier has higher precedence than one with (so `1.0.0-alpha` < `1.0.0`).
+fn compare_prerelease(a: &Version, b: &Version) -> Ordering {
+    match (&a.prerelease, &b.prerelease) {
+        (Prerelease::Identifier(a), Prerelease::Identifier(b)) => a.cmp(b),
+        (Prerelease::Identifier(_), Prerelease::Empty) => Ordering::Less,
+        (Prerelease::Empty, Prerelease::Identifier(_)) => Ordering::Greater,
+        (Prerelease::Empty, Prerelease::Empty) => Ordering::Equal,
+    }
+}
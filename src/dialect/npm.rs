@@ -0,0 +1,94 @@
+use crate::dialect::DialectParser;
+
+pub struct Npm;
+
+impl DialectParser for Npm {
+    /// Strip a single leading `v` or `V` from the version string, if one is present.
+    fn parse_prefix(version_bytes: &[u8]) -> (Option<char>, &[u8]) {
+        match version_bytes.first() {
+            Some(prefix @ (b'v' | b'V')) => (Some(*prefix as char), &version_bytes[1..]),
+            _ => (None, version_bytes),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::{Dialect, Error, PartType, Requirement, Version};
+
+    #[test]
+    fn should_parse_lowercase_prefix() {
+        let version = Version::parse("v0.0.0", Dialect::Npm).unwrap();
+
+        assert_eq!(version.major, 0);
+        assert_eq!(version.minor, 0);
+        assert_eq!(version.patch, 0);
+        assert_eq!(version.to_string(), "v0.0.0");
+    }
+
+    #[test]
+    fn should_parse_uppercase_prefix() {
+        let version = Version::parse("V10.20.30", Dialect::Npm).unwrap();
+
+        assert_eq!(version.major, 10);
+        assert_eq!(version.minor, 20);
+        assert_eq!(version.patch, 30);
+        assert_eq!(version.to_string(), "V10.20.30");
+    }
+
+    #[test]
+    fn should_parse_without_prefix() {
+        let version = Version::parse("1.2.3-alpha", Dialect::Npm).unwrap();
+
+        assert_eq!(version.to_string(), "1.2.3-alpha");
+    }
+
+    #[test]
+    fn should_fail_repeated_prefix() {
+        let version = Version::parse("vv1.2.3", Dialect::Npm);
+
+        assert_eq!(
+            version,
+            Err(Error::InvalidCharacter {
+                part: PartType::Major,
+                byte: b'v',
+                position: 1
+            })
+        );
+    }
+
+    #[test]
+    fn should_fail_prefix_in_standard_dialect() {
+        let version = Version::parse("v1.2.3", Dialect::Standard);
+
+        assert_eq!(
+            version,
+            Err(Error::InvalidCharacter {
+                part: PartType::Major,
+                byte: b'v',
+                position: 0
+            })
+        );
+    }
+
+    #[test]
+    fn should_match_prerelease_ranges_like_standard() {
+        for requirement in [">=1.2.3-alpha.3", ">1.2.3", "^1.2.3", "<2.0.0"] {
+            for version in ["1.2.3-alpha.7", "1.2.4", "3.4.5-alpha.9", "2.0.0-rc.1"] {
+                assert_eq!(
+                    Requirement::parse(requirement, Dialect::Npm)
+                        .unwrap()
+                        .matches(&Version::parse(version, Dialect::Npm).unwrap()),
+                    Requirement::parse(requirement, Dialect::Standard)
+                        .unwrap()
+                        .matches(&Version::parse(version, Dialect::Standard).unwrap()),
+                    "{} {}",
+                    requirement,
+                    version
+                );
+            }
+        }
+    }
+}
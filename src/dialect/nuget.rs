@@ -0,0 +1,390 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::Write;
+use core::hash::{Hash, Hasher};
+
+use crate::component::PartType;
+use crate::dialect::{Dialect, DialectParser, Extension, Standard};
+use crate::error::Error;
+use crate::{Prerelease, PrereleaseComponent, Version};
+
+pub struct NuGet;
+
+/// The fourth numeric part of a NuGet version (like `4` in `1.2.3.4`), which follows the patch version.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Release {
+    revision: usize,
+}
+
+impl DialectParser for NuGet {
+    /// Parse a version string following the NuGet version format.
+    ///
+    /// The version follows the standard dialect, but may include a fourth numeric part (the revision)
+    /// after the patch version (like `1.2.3.4-beta`). Errors in the revision are reported against the
+    /// patch part.
+    fn parse(version: &str, dialect: Dialect) -> Result<Version, Error> {
+        let (revision, remaining) = NuGet::split_revision(version)?;
+
+        Version::parse_parts(&remaining, dialect)
+            .map(|version| NuGet::with_revision(version, revision))
+            .map_err(|error| NuGet::offset_error(error, version, &remaining))
+    }
+
+    /// Parse raw bytes following the NuGet version format.
+    ///
+    /// The revision is split out of a version string, so the bytes are converted into a string first.
+    fn parse_bytes(version: &[u8], dialect: Dialect) -> Result<Version, Error> {
+        Self::parse(Version::from_utf8(version)?, dialect)
+    }
+
+    /// Parse a version string following the NuGet version format, collecting every error rather than
+    /// returning the first.
+    fn parse_collecting(version: &str, dialect: Dialect) -> Result<Version, Vec<Error>> {
+        let (revision, remaining) = match NuGet::split_revision(version) {
+            Ok(split) => split,
+            Err(error) => return Err(vec![error]),
+        };
+
+        Version::parse_parts_collecting(&remaining, dialect)
+            .map(|version| NuGet::with_revision(version, revision))
+            .map_err(|errors| {
+                errors
+                    .into_iter()
+                    .map(|error| NuGet::offset_error(error, version, &remaining))
+                    .collect()
+            })
+    }
+
+    /// Validate a version string following the NuGet version format.
+    ///
+    /// Unlike the standard dialect, versions with a revision require allocating while they're validated.
+    fn validate(version: &str, dialect: Dialect) -> Result<(), Error> {
+        let (_, remaining) = NuGet::split_revision(version)?;
+
+        Version::validate_parts(&remaining, dialect)
+            .map_err(|error| NuGet::offset_error(error, version, &remaining))
+    }
+
+    /// Compare two versions following the standard precedence rules, with the revision compared
+    /// immediately after the patch version.
+    ///
+    /// A missing revision is treated as zero (so `1.2.3` == `1.2.3.0`), and release labels are
+    /// compared case-insensitively (so `1.0.0-Beta` == `1.0.0-beta`).
+    ///
+    /// For example: `1.2.3-beta` < `1.2.3` < `1.2.3.1-beta` < `1.2.3.1` < `1.2.4`
+    fn cmp(a: &Version, b: &Version) -> Ordering {
+        a.core()
+            .cmp(&b.core())
+            .then_with(|| NuGet::revision(a).cmp(&NuGet::revision(b)))
+            .then_with(
+                || match (NuGet::release_label(a), NuGet::release_label(b)) {
+                    (Some(a), Some(b)) => a.cmp(&b),
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => Ordering::Equal,
+                },
+            )
+    }
+
+    /// Hash the parts of a version which are used when comparing, including the revision and the
+    /// release label without its case.
+    fn hash<H: Hasher>(version: &Version, state: &mut H) {
+        version.core().hash(state);
+        NuGet::revision(version).hash(state);
+        NuGet::release_label(version).hash(state);
+    }
+
+    /// Report a different revision as the patch version, as it's compared immediately after it.
+    fn diff(a: &Version, b: &Version) -> Option<PartType> {
+        (NuGet::revision(a) != NuGet::revision(b)).then_some(PartType::Patch)
+    }
+
+    /// Compare two versions and decide if they're considered equal, including their revision.
+    fn eq(a: &Version, b: &Version) -> bool {
+        NuGet::cmp(a, b) == Ordering::Equal
+    }
+
+    /// Write a version, including the revision only when it was specified.
+    ///
+    /// The revision is inserted into the standard format, so this allocates the formatted string.
+    fn write_to<W: Write>(version: &Version, w: &mut W) -> core::fmt::Result {
+        w.write_str(&Self::format(version))
+    }
+
+    /// Normalize a version into the standard canonical form, including the revision only when it
+    /// isn't zero (so `1.2.3.0` and `1.2.3` are both normalized to `1.2.3`), and with the release
+    /// label in lowercase.
+    fn canonicalize(version: &Version) -> String {
+        let mut string = Standard::canonicalize(version);
+        string.make_ascii_lowercase();
+
+        let revision = NuGet::revision(version);
+
+        if revision > 0 {
+            let end = string.find('-').unwrap_or(string.len());
+            string.insert_str(end, &format!(".{}", revision));
+        }
+
+        string
+    }
+
+    /// Format a version back into a human-readable string, including the revision only when it
+    /// was specified.
+    fn format(version: &Version) -> String {
+        let mut string = Standard::format(version);
+
+        if let Some(Extension::NuGet(release)) = &version.extension {
+            // The major, minor and patch versions never contain a hyphen or plus, so the revision
+            // always belongs just before the first one
+            let end = string.find(['-', '+']).unwrap_or(string.len());
+            string.insert_str(end, &format!(".{}", release.revision));
+        }
+
+        string
+    }
+}
+
+impl NuGet {
+    /// Split the revision out of a version string, returning it alongside the remaining version
+    /// string (like `4` and `1.2.3-beta` for `1.2.3.4-beta`).
+    fn split_revision(version: &str) -> Result<(Option<usize>, String), Error> {
+        let end = version.find(['-', '+']).unwrap_or(version.len());
+
+        let start = match version[..end].match_indices('.').nth(2) {
+            Some((dot, _)) => dot,
+            None => return Ok((None, String::from(version))),
+        };
+
+        let revision = &version[start + 1..end];
+
+        if revision.is_empty() {
+            return Err(Error::EmptyPart(PartType::Patch));
+        }
+
+        if let Some(position) = revision.bytes().position(|byte| !byte.is_ascii_digit()) {
+            return Err(Error::InvalidCharacter {
+                part: PartType::Patch,
+                byte: revision.as_bytes()[position],
+                position: start + 1 + position,
+            });
+        }
+
+        if revision.len() > 1 && revision.starts_with('0') {
+            return Err(Error::InvalidPrecedingZero(PartType::Patch));
+        }
+
+        let revision = revision
+            .parse()
+            .map_err(|_| Error::NumberOverflow(PartType::Patch))?;
+
+        Ok((
+            Some(revision),
+            format!("{}{}", &version[..start], &version[end..]),
+        ))
+    }
+
+    /// Record the revision (if there was one) against a parsed version.
+    fn with_revision(mut version: Version, revision: Option<usize>) -> Version {
+        version.extension = revision.map(|revision| Extension::NuGet(Release { revision }));
+        version
+    }
+
+    /// Move the position of an error found in the remaining version string, so that it points at the
+    /// same byte in the original version string.
+    fn offset_error(error: Error, version: &str, remaining: &str) -> Error {
+        let removed = version.len() - remaining.len();
+        let start = version.find(['-', '+']).unwrap_or(version.len()) - removed;
+
+        match error {
+            Error::InvalidCharacter {
+                part,
+                byte,
+                position,
+            } if removed > 0 && position >= start => Error::InvalidCharacter {
+                part,
+                byte,
+                position: position + removed,
+            },
+            error => error,
+        }
+    }
+
+    /// The revision of a version, or zero when it wasn't specified.
+    fn revision(version: &Version) -> usize {
+        match &version.extension {
+            Some(Extension::NuGet(release)) => release.revision,
+            _ => 0,
+        }
+    }
+
+    /// The prerelease identifier (the release label) of a version in lowercase, as NuGet compares
+    /// release labels case-insensitively.
+    fn release_label(version: &Version) -> Option<Vec<PrereleaseComponent>> {
+        match &version.prerelease {
+            Prerelease::Empty => None,
+            Prerelease::Identifier(components) => Some(
+                components
+                    .iter()
+                    .map(|component| match component {
+                        PrereleaseComponent::String(label) => {
+                            PrereleaseComponent::String(label.to_ascii_lowercase())
+                        }
+                        component => component.clone(),
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec;
+    use core::cmp::Ordering;
+
+    use crate::{Dialect, Error, PartType, Version};
+
+    #[test]
+    fn should_parse_revision() {
+        let version = Version::parse("1.2.3.4-beta+build", Dialect::NuGet).unwrap();
+
+        assert_eq!(version.core(), (1, 2, 3));
+        assert!(version.is_prerelease());
+        assert_eq!(version.to_string(), "1.2.3.4-beta+build");
+    }
+
+    #[test]
+    fn should_only_format_revision_when_present() {
+        assert_eq!(
+            Version::parse("1.2.3", Dialect::NuGet).unwrap().to_string(),
+            "1.2.3"
+        );
+        assert_eq!(
+            Version::parse("1.2.3.0", Dialect::NuGet)
+                .unwrap()
+                .to_string(),
+            "1.2.3.0"
+        );
+    }
+
+    #[test]
+    fn should_compare_revision_after_patch() {
+        let revision = Version::parse("1.2.3.4", Dialect::NuGet).unwrap();
+
+        assert!(revision > Version::parse("1.2.3", Dialect::NuGet).unwrap());
+        assert!(revision < Version::parse("1.2.4", Dialect::NuGet).unwrap());
+        assert!(revision > Version::parse("1.2.3.4-beta", Dialect::NuGet).unwrap());
+        assert_eq!(
+            Version::parse("1.2.3.0", Dialect::NuGet).unwrap(),
+            Version::parse("1.2.3", Dialect::NuGet).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_order_prerelease_below_release() {
+        assert!(
+            Version::parse("1.0.0-beta", Dialect::NuGet).unwrap()
+                < Version::parse("1.0.0", Dialect::NuGet).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_fail_invalid_revision() {
+        for (version, error) in [
+            ("1.2.3.", Error::EmptyPart(PartType::Patch)),
+            ("1.2.3.01", Error::InvalidPrecedingZero(PartType::Patch)),
+            (
+                "1.2.3.4.5",
+                Error::InvalidCharacter {
+                    part: PartType::Patch,
+                    byte: b'.',
+                    position: 7,
+                },
+            ),
+            (
+                "1.2.3.4-beta$",
+                Error::InvalidCharacter {
+                    part: PartType::Prerelease,
+                    byte: b'$',
+                    position: 12,
+                },
+            ),
+        ] {
+            assert_eq!(
+                Version::parse(version, Dialect::NuGet),
+                Err(error),
+                "{}",
+                version
+            );
+            assert_eq!(
+                Version::validate(version, Dialect::NuGet),
+                Version::parse(version, Dialect::NuGet).map(|_| ())
+            );
+        }
+    }
+
+    #[test]
+    fn should_collect_errors_at_original_positions() {
+        assert_eq!(
+            Version::parse_collecting("1.2.3.4-be$ta+bu$ild", Dialect::NuGet),
+            Err(vec![
+                Error::InvalidCharacter {
+                    part: PartType::Prerelease,
+                    byte: b'$',
+                    position: 10,
+                },
+                Error::InvalidCharacter {
+                    part: PartType::BuildMetadata,
+                    byte: b'$',
+                    position: 16,
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn should_drop_revision_when_bumped() {
+        let version = Version::parse("1.2.3.4", Dialect::NuGet).unwrap();
+
+        assert_eq!(version.bump_patch().to_string(), "1.2.4");
+    }
+
+    #[test]
+    fn should_compare_release_labels_case_insensitively() {
+        extern crate std;
+
+        use core::hash::BuildHasher;
+        use std::collections::hash_map::RandomState;
+
+        let state = RandomState::new();
+
+        for (a, b) in [
+            ("1.0.0-Beta", "1.0.0-beta"),
+            ("1.0.0-RC.1", "1.0.0-rc.1"),
+            ("1.2.3.4-ALPHA", "1.2.3.4-alpha+build"),
+        ] {
+            let (a, b) = (
+                Version::parse(a, Dialect::NuGet).unwrap(),
+                Version::parse(b, Dialect::NuGet).unwrap(),
+            );
+
+            assert_eq!(a, b);
+            assert_eq!(a.partial_cmp(&b), Some(Ordering::Equal));
+            assert_eq!(state.hash_one(&a), state.hash_one(&b), "{} and {}", a, b);
+            assert_eq!(a.canonicalize(), b.canonicalize());
+        }
+
+        assert!(
+            Version::parse("1.0.0-Alpha", Dialect::NuGet).unwrap()
+                < Version::parse("1.0.0-beta", Dialect::NuGet).unwrap()
+        );
+        assert!(
+            Version::parse("1.0.0-BETA", Dialect::NuGet).unwrap()
+                < Version::parse("1.0.0", Dialect::NuGet).unwrap()
+        );
+    }
+}
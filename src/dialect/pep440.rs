@@ -0,0 +1,663 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::dialect::cursor::Cursor;
+use crate::dialect::{Dialect, DialectParser, Extension};
+use crate::error::Error;
+use crate::{BuildMetadata, PartType, Prerelease, PrereleaseComponent, Version};
+
+pub struct Pep440;
+
+/// The parts of a PEP 440 version which can't be represented by the standard version parts.
+///
+/// The pre and development releases are represented by the prerelease identifier (like `a.1.dev.2`), and
+/// the local version by the build metadata.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Release {
+    epoch: usize,
+    specified_segments: usize,
+    extra_segments: Vec<usize>,
+    post: Option<usize>,
+}
+
+/// A complete view of a PEP 440 version, used for comparing and formatting.
+struct Parts {
+    epoch: usize,
+    segments: Vec<usize>,
+    pre: Option<(Phase, usize)>,
+    post: Option<usize>,
+    dev: Option<usize>,
+}
+
+/// The phase of a pre-release, in order of precedence.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Phase {
+    Alpha,
+    Beta,
+    ReleaseCandidate,
+}
+
+impl Phase {
+    /// The normalized label used for the phase.
+    fn label(&self) -> &'static str {
+        match self {
+            Phase::Alpha => "a",
+            Phase::Beta => "b",
+            Phase::ReleaseCandidate => "rc",
+        }
+    }
+}
+
+impl DialectParser for Pep440 {
+    const PARSES_WHOLE_STRING: bool = true;
+
+    /// Parse a version string following the PEP 440 specification.
+    ///
+    /// Alternative spellings permitted by the specification (like `1.0-ALPHA.1` or `1.0-1`) are
+    /// accepted, and normalized (to `1.0a1` and `1.0.post1` respectively).
+    fn parse(version: &str, dialect: Dialect) -> Result<Version, Error> {
+        let normalized = version.trim().to_ascii_lowercase();
+        let mut cursor = Cursor {
+            bytes: normalized.as_bytes(),
+            position: 0,
+            leading_whitespace: version.len() - version.trim_start().len(),
+        };
+
+        cursor.eat(b"v");
+
+        let mut epoch = 0;
+        let mut segments = vec![cursor.required_number(PartType::Major)?];
+
+        if cursor.eat(b"!") {
+            epoch = segments.pop().unwrap_or_default();
+            segments.push(cursor.required_number(PartType::Major)?);
+        }
+
+        while cursor.peek() == Some(b'.') && cursor.peek_at(1).is_some_and(|b| b.is_ascii_digit()) {
+            cursor.position += 1;
+            segments.push(cursor.required_number(match segments.len() {
+                1 => PartType::Minor,
+                _ => PartType::Patch,
+            })?);
+        }
+
+        let pre = cursor.optional(|cursor| {
+            cursor.eat_separator();
+
+            let phase = if cursor.eat(b"alpha") || cursor.eat(b"a") {
+                Phase::Alpha
+            } else if cursor.eat(b"beta") || cursor.eat(b"b") {
+                Phase::Beta
+            } else if cursor.eat(b"rc")
+                || cursor.eat(b"c")
+                || cursor.eat(b"preview")
+                || cursor.eat(b"pre")
+            {
+                Phase::ReleaseCandidate
+            } else {
+                return Ok(None);
+            };
+
+            cursor.eat_separator();
+
+            Ok(Some((
+                phase,
+                cursor.number(PartType::Prerelease)?.unwrap_or(0),
+            )))
+        })?;
+
+        let post = cursor.optional(|cursor| {
+            if cursor.eat(b"-") {
+                // An implicit post release (like 1.0-1) must always include the number
+                if let Some(post) = cursor.number(PartType::Prerelease)? {
+                    return Ok(Some(post));
+                }
+            }
+
+            cursor.eat_separator();
+
+            if cursor.eat(b"post") || cursor.eat(b"rev") || cursor.eat(b"r") {
+                cursor.eat_separator();
+
+                return Ok(Some(cursor.number(PartType::Prerelease)?.unwrap_or(0)));
+            }
+
+            Ok(None)
+        })?;
+
+        let dev = cursor.optional(|cursor| {
+            cursor.eat_separator();
+
+            if cursor.eat(b"dev") {
+                cursor.eat_separator();
+
+                return Ok(Some(cursor.number(PartType::Prerelease)?.unwrap_or(0)));
+            }
+
+            Ok(None)
+        })?;
+
+        let local = if cursor.eat(b"+") {
+            Some(parse_local(cursor.remaining(), cursor.offset())?)
+        } else if let Some(byte) = cursor.peek() {
+            return Err(Error::InvalidCharacter {
+                part: if pre.is_none() && post.is_none() && dev.is_none() && segments.len() == 1 {
+                    PartType::Major
+                } else {
+                    PartType::Prerelease
+                },
+                byte,
+                position: cursor.offset(),
+            });
+        } else {
+            None
+        };
+
+        let mut version = Version::new(
+            segments[0],
+            segments.get(1).copied().unwrap_or(0),
+            segments.get(2).copied().unwrap_or(0),
+            prerelease_components(pre, dev),
+            local,
+            None,
+            dialect,
+        );
+        version.extension = Some(Extension::Pep440(Release {
+            epoch,
+            specified_segments: segments.len(),
+            extra_segments: segments.iter().skip(3).copied().collect(),
+            post,
+        }));
+
+        Ok(version)
+    }
+
+    /// Skip the epoch (like `2!` in `2!1.0`), as it's not one of the release segments.
+    fn strip_epoch(version: &str) -> &str {
+        version
+            .split_once('!')
+            .map_or(version, |(_, release)| release)
+    }
+
+    /// Report a different epoch as the major version, as it's more significant than every release
+    /// segment, and any different release segments after the patch version (or post release) as
+    /// the patch version.
+    fn diff(a: &Version, b: &Version) -> Option<PartType> {
+        let (a, b) = (parts(a), parts(b));
+
+        if a.epoch != b.epoch {
+            Some(PartType::Major)
+        } else if compare_segments(&a.segments, &b.segments) != Ordering::Equal || a.post != b.post
+        {
+            Some(PartType::Patch)
+        } else {
+            None
+        }
+    }
+
+    /// Keep the epoch, as changing the release segments doesn't move a version out of its epoch. Any
+    /// release segments after the patch version, and the post release, no longer apply.
+    fn retain_extension(version: &Version, _changed: &Version) -> Option<Extension> {
+        match &version.extension {
+            Some(Extension::Pep440(release)) => Some(Extension::Pep440(Release {
+                epoch: release.epoch,
+                specified_segments: 3,
+                extra_segments: vec![],
+                post: None,
+            })),
+            _ => None,
+        }
+    }
+
+    /// Compare two versions following the PEP 440 precedence rules.
+    ///
+    /// For example: `1.0.dev1` < `1.0a1` < `1.0b1` < `1.0rc1` < `1.0` < `1.0.post1` < `1.0+local` < `1!0.1`
+    fn cmp(a: &Version, b: &Version) -> Ordering {
+        let (a_release, b_release) = (parts(a), parts(b));
+
+        a_release
+            .epoch
+            .cmp(&b_release.epoch)
+            .then_with(|| compare_segments(&a_release.segments, &b_release.segments))
+            .then_with(|| pre_key(&a_release).cmp(&pre_key(&b_release)))
+            .then_with(|| post_key(&a_release).cmp(&post_key(&b_release)))
+            .then_with(|| dev_key(&a_release).cmp(&dev_key(&b_release)))
+            .then_with(|| compare_local(&a.build_metadata, &b.build_metadata))
+    }
+
+    /// Compare two versions and decide if they're considered equal, following the PEP 440 precedence rules.
+    ///
+    /// Unlike SemVer, local versions are significant when deciding equality.
+    fn eq(a: &Version, b: &Version) -> bool {
+        Pep440::cmp(a, b) == Ordering::Equal
+    }
+
+    /// Format a version back into its normalized PEP 440 form.
+    fn format(version: &Version) -> String {
+        let mut string = format_release(&parts(version));
+
+        if let BuildMetadata::Identifier(local) = &version.build_metadata {
+            string.push_str(&format!("+{}", local));
+        }
+
+        string
+    }
+
+    /// Normalize a version into its PEP 440 form, with exactly three release segments (or more, when
+    /// any later segment isn't zero), and without the local version.
+    fn canonicalize(version: &Version) -> String {
+        let mut release = parts(version);
+
+        release.segments.resize(release.segments.len().max(3), 0);
+
+        while release.segments.len() > 3 && release.segments.last() == Some(&0) {
+            release.segments.pop();
+        }
+
+        format_release(&release)
+    }
+}
+
+/// Format every part of a PEP 440 version, except for the local version.
+fn format_release(release: &Parts) -> String {
+    let mut string = String::new();
+
+    if release.epoch > 0 {
+        string.push_str(&format!("{}!", release.epoch));
+    }
+
+    string.push_str(
+        &release
+            .segments
+            .iter()
+            .map(|segment| segment.to_string())
+            .collect::<Vec<_>>()
+            .join("."),
+    );
+
+    if let Some((phase, number)) = release.pre {
+        string.push_str(&format!("{}{}", phase.label(), number));
+    }
+
+    if let Some(post) = release.post {
+        string.push_str(&format!(".post{}", post));
+    }
+
+    if let Some(dev) = release.dev {
+        string.push_str(&format!(".dev{}", dev));
+    }
+
+    string
+}
+
+/// Validate and normalize a local version label (like `ubuntu-1` to `ubuntu.1`).
+///
+/// The position is the byte offset of the label inside the version string, used when reporting errors.
+fn parse_local(local: &[u8], position: usize) -> Result<String, Error> {
+    let mut normalized = String::new();
+    let mut segment_position = position;
+
+    for segment in local.split(|byte| matches!(byte, b'.' | b'-' | b'_')) {
+        if segment.is_empty() {
+            return Err(Error::EmptyPart(PartType::BuildMetadata));
+        }
+
+        if let Some(i) = segment
+            .iter()
+            .position(|byte| !byte.is_ascii_alphanumeric())
+        {
+            return Err(Error::InvalidCharacter {
+                part: PartType::BuildMetadata,
+                byte: segment[i],
+                position: segment_position + i,
+            });
+        }
+
+        segment_position += segment.len() + 1;
+
+        if !normalized.is_empty() {
+            normalized.push('.');
+        }
+
+        normalized.push_str(
+            core::str::from_utf8(segment)
+                .map_err(|_| Error::InvalidUtf8(PartType::BuildMetadata))?,
+        );
+    }
+
+    Ok(normalized)
+}
+
+/// Represent the pre and development releases as prerelease components, so that they're
+/// visible as a prerelease identifier (like `a.1.dev.2`).
+fn prerelease_components(
+    pre: Option<(Phase, usize)>,
+    dev: Option<usize>,
+) -> Option<Vec<PrereleaseComponent>> {
+    let mut components = vec![];
+
+    if let Some((phase, number)) = pre {
+        components.push(PrereleaseComponent::String(phase.label().to_string()));
+        components.push(PrereleaseComponent::Number(number));
+    }
+
+    if let Some(dev) = dev {
+        components.push(PrereleaseComponent::String("dev".to_string()));
+        components.push(PrereleaseComponent::Number(dev));
+    }
+
+    if components.is_empty() {
+        None
+    } else {
+        Some(components)
+    }
+}
+
+/// Interpret the complete PEP 440 parts of a version.
+///
+/// Versions which weren't parsed using the dialect (for example, when bumping a version) are
+/// interpreted from their standard parts alone.
+fn parts(version: &Version) -> Parts {
+    let release = match &version.extension {
+        Some(Extension::Pep440(release)) => release.clone(),
+        _ => Release {
+            epoch: 0,
+            specified_segments: 3,
+            extra_segments: vec![],
+            post: None,
+        },
+    };
+
+    let mut segments = vec![version.major, version.minor, version.patch];
+    segments.extend(release.extra_segments);
+
+    // Only keep the trailing zero segments which were originally specified (like 1.0)
+    while segments.len() > release.specified_segments.max(1) && segments.last() == Some(&0) {
+        segments.pop();
+    }
+
+    let mut parts = Parts {
+        epoch: release.epoch,
+        segments,
+        pre: None,
+        post: release.post,
+        dev: None,
+    };
+
+    if let Prerelease::Identifier(components) = &version.prerelease {
+        for pair in components.chunks(2) {
+            if let [PrereleaseComponent::String(label), PrereleaseComponent::Number(number)] = pair
+            {
+                match label.as_str() {
+                    "a" => parts.pre = Some((Phase::Alpha, *number)),
+                    "b" => parts.pre = Some((Phase::Beta, *number)),
+                    "rc" => parts.pre = Some((Phase::ReleaseCandidate, *number)),
+                    "dev" => parts.dev = Some(*number),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    parts
+}
+
+/// Compare release segments, treating any missing trailing segments as zero (so `1.0` == `1.0.0`).
+fn compare_segments(a: &[usize], b: &[usize]) -> Ordering {
+    (0..a.len().max(b.len()))
+        .map(|i| {
+            a.get(i)
+                .copied()
+                .unwrap_or(0)
+                .cmp(&b.get(i).copied().unwrap_or(0))
+        })
+        .find(|ordering| ordering != &Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+/// A development release without a pre or post release (like `1.0.dev1`) sorts before any pre-release,
+/// and a final release sorts after them.
+fn pre_key(release: &Parts) -> (u8, Option<(Phase, usize)>) {
+    match release.pre {
+        None if release.post.is_none() && release.dev.is_some() => (0, None),
+        Some(pre) => (1, Some(pre)),
+        None => (2, None),
+    }
+}
+
+/// A post release sorts after the same release without one.
+fn post_key(release: &Parts) -> Option<usize> {
+    release.post
+}
+
+/// A development release sorts before the same release without one.
+fn dev_key(release: &Parts) -> (bool, usize) {
+    match release.dev {
+        Some(dev) => (false, dev),
+        None => (true, 0),
+    }
+}
+
+/// Compare local versions one segment at a time.
+///
+/// Numeric segments are compared numerically, and sort after alphanumeric segments, which are
+/// compared lexically. A version without a local version sorts before one with it.
+fn compare_local(a: &BuildMetadata, b: &BuildMetadata) -> Ordering {
+    match (a, b) {
+        (BuildMetadata::Empty, BuildMetadata::Empty) => Ordering::Equal,
+        (BuildMetadata::Empty, BuildMetadata::Identifier(_)) => Ordering::Less,
+        (BuildMetadata::Identifier(_), BuildMetadata::Empty) => Ordering::Greater,
+        (BuildMetadata::Identifier(a), BuildMetadata::Identifier(b)) => {
+            let key = |segment: &str| match segment.parse::<usize>() {
+                Ok(number) => (true, number, String::new()),
+                Err(_) => (false, 0, segment.to_string()),
+            };
+
+            a.split('.').map(key).cmp(b.split('.').map(key))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    use crate::{BuildMetadata, Dialect, Error, PartType, Requirement, Version};
+
+    #[test]
+    fn should_parse_release_segments() {
+        let version = Version::parse("1.2.3.4", Dialect::Pep440).unwrap();
+
+        assert_eq!(version.core(), (1, 2, 3));
+        assert!(!version.is_prerelease());
+        assert_eq!(version.to_string(), "1.2.3.4");
+    }
+
+    #[test]
+    fn should_parse_every_part() {
+        let version = Version::parse("2!1.0rc1.post2.dev3+ubuntu.1", Dialect::Pep440).unwrap();
+
+        assert_eq!(version.core(), (1, 0, 0));
+        assert!(version.is_prerelease());
+        assert_eq!(
+            version.build_metadata,
+            BuildMetadata::Identifier("ubuntu.1".to_string())
+        );
+        assert_eq!(version.to_string(), "2!1.0rc1.post2.dev3+ubuntu.1");
+    }
+
+    #[test]
+    fn should_normalize_alternative_spellings() {
+        for (version, normalized) in [
+            ("v1.0-ALPHA.1", "1.0a1"),
+            ("1.0beta", "1.0b0"),
+            ("1.0c2", "1.0rc2"),
+            ("1.0-1", "1.0.post1"),
+            ("1.0_rev2", "1.0.post2"),
+            ("1.0-dev", "1.0.dev0"),
+            ("1.0+Ubuntu-1_2", "1.0+ubuntu.1.2"),
+        ] {
+            assert_eq!(
+                Version::parse(version, Dialect::Pep440)
+                    .unwrap()
+                    .to_string(),
+                normalized
+            );
+        }
+    }
+
+    #[test]
+    fn should_order_by_epoch_first() {
+        let epoch_1 = Version::parse("1!1.0", Dialect::Pep440).unwrap();
+        let epoch_2 = Version::parse("2!0.1", Dialect::Pep440).unwrap();
+        let no_epoch = Version::parse("9.9", Dialect::Pep440).unwrap();
+
+        assert!(no_epoch < epoch_1);
+        assert!(epoch_1 < epoch_2);
+    }
+
+    #[test]
+    fn should_order_prerelease_below_release() {
+        let prerelease = Version::parse("1.0a1", Dialect::Pep440).unwrap();
+        let release = Version::parse("1.0", Dialect::Pep440).unwrap();
+
+        assert!(prerelease < release);
+    }
+
+    #[test]
+    fn should_order_following_precedence() {
+        let versions = [
+            "1.0.dev1",
+            "1.0a1.dev1",
+            "1.0a1",
+            "1.0a2",
+            "1.0b1",
+            "1.0rc1",
+            "1.0",
+            "1.0+abc",
+            "1.0+5",
+            "1.0.post1.dev1",
+            "1.0.post1",
+            "1.0.1",
+        ]
+        .iter()
+        .map(|version| Version::parse(version, Dialect::Pep440).unwrap())
+        .collect::<Vec<_>>();
+
+        for pair in versions.windows(2) {
+            assert!(pair[0] < pair[1], "{} < {}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn should_treat_missing_segments_as_zero() {
+        assert_eq!(
+            Version::parse("1.0", Dialect::Pep440).unwrap(),
+            Version::parse("1.0.0", Dialect::Pep440).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_fail_invalid_versions() {
+        for (version, error) in [
+            ("", Error::EmptyPart(PartType::Major)),
+            (
+                "abc",
+                Error::InvalidCharacter {
+                    part: PartType::Major,
+                    byte: b'a',
+                    position: 0,
+                },
+            ),
+            (
+                "1.0foo",
+                Error::InvalidCharacter {
+                    part: PartType::Prerelease,
+                    byte: b'f',
+                    position: 3,
+                },
+            ),
+            ("1.0+", Error::EmptyPart(PartType::BuildMetadata)),
+            ("1.0+a..b", Error::EmptyPart(PartType::BuildMetadata)),
+            (
+                "1.0+a$b",
+                Error::InvalidCharacter {
+                    part: PartType::BuildMetadata,
+                    byte: b'$',
+                    position: 5,
+                },
+            ),
+        ] {
+            assert_eq!(
+                Version::parse(version, Dialect::Pep440),
+                Err(error),
+                "{}",
+                version
+            );
+        }
+    }
+
+    #[test]
+    fn should_remove_pre_and_development_releases() {
+        let version = Version::parse("1.0rc1.post1.dev2+local", Dialect::Pep440).unwrap();
+
+        assert_eq!(version.without_prerelease().to_string(), "1.0.post1+local");
+        assert_eq!(
+            version.without_build_metadata().to_string(),
+            "1.0rc1.post1.dev2"
+        );
+    }
+
+    #[test]
+    fn should_interpret_bumped_versions() {
+        let version = Version::parse("1.0rc1", Dialect::Pep440).unwrap();
+
+        assert_eq!(version.bump_minor().to_string(), "1.1.0");
+        assert!(version < version.bump_patch());
+    }
+
+    #[test]
+    fn should_keep_epoch_when_bumped() {
+        for version in ["2!1.0", "1!2.0.post1", "1!1.2.3.4", "3!0.1rc1", "1.0.post1"] {
+            let version = Version::parse(version, Dialect::Pep440).unwrap();
+
+            for bumped in [
+                version.bump_major(),
+                version.bump_minor(),
+                version.bump_patch(),
+            ] {
+                assert!(bumped > version, "{} > {}", bumped, version);
+            }
+        }
+
+        let version = Version::parse("2!1.0", Dialect::Pep440).unwrap();
+        assert_eq!(version.bump_major().to_string(), "2!2.0.0");
+    }
+
+    #[test]
+    fn should_match_ranges_within_epoch() {
+        let caret = Requirement::parse("^1!2.0", Dialect::Pep440).unwrap();
+        let tilde = Requirement::parse("~1!2.0", Dialect::Pep440).unwrap();
+
+        for version in ["1!2.0", "1!2.0.5"] {
+            let version = Version::parse(version, Dialect::Pep440).unwrap();
+
+            assert!(caret.matches(&version), "^1!2.0 matches {}", version);
+            assert!(tilde.matches(&version), "~1!2.0 matches {}", version);
+        }
+
+        let version = Version::parse("1!2.5", Dialect::Pep440).unwrap();
+        assert!(caret.matches(&version));
+        assert!(!tilde.matches(&version));
+
+        for version in ["2.5", "1!3.0", "2!2.5"] {
+            let version = Version::parse(version, Dialect::Pep440).unwrap();
+
+            assert!(!caret.matches(&version), "^1!2.0 doesn't match {}", version);
+        }
+    }
+}
@@ -0,0 +1,448 @@
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+use crate::component::PartType;
+use crate::dialect::{Dialect, DialectParser, Extension};
+use crate::error::Error;
+use crate::{Prerelease, PrereleaseComponent, Version};
+
+pub struct RubyGems;
+
+/// The release segments of a RubyGems version which can't be represented by the standard major, minor
+/// and patch versions (like `4.5` in `1.2.3.4.5`).
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Release {
+    specified_segments: usize,
+    extra_segments: Vec<usize>,
+}
+
+impl DialectParser for RubyGems {
+    const PARSES_WHOLE_STRING: bool = true;
+
+    /// Parse a version string following the RubyGems version format.
+    ///
+    /// Versions are made up of any number of dot-separated segments (like `1.2.3.4.5`), where the first
+    /// segment must be numeric. The first segment containing a letter begins the prerelease (like `pre.1`
+    /// in `1.0.pre.1`), and a hyphen is treated as `.pre.` (so `1.0-rc1` is normalized to `1.0.pre.rc.1`).
+    ///
+    /// Like RubyGems, segments are split wherever letters and digits meet (so `1.0a1` is made up of the
+    /// `1`, `0`, `a` and `1` segments).
+    fn parse(version: &str, dialect: Dialect) -> Result<Version, Error> {
+        let bytes = version.as_bytes();
+        let mut segments = Segments {
+            release: vec![],
+            prerelease: vec![],
+        };
+        let mut segment_start = 0;
+        let mut run_start: Option<usize> = None;
+
+        for (position, byte) in bytes.iter().enumerate() {
+            if byte == &b'.' || byte == &b'-' {
+                if position == segment_start {
+                    return Err(Error::EmptyPart(segments.part()));
+                }
+
+                if let Some(start) = run_start.take() {
+                    segments.push(&bytes[start..position])?;
+                }
+
+                if byte == &b'-' {
+                    segments
+                        .prerelease
+                        .push(PrereleaseComponent::String("pre".to_string()));
+                }
+
+                segment_start = position + 1;
+                continue;
+            }
+
+            let is_first_segment = segments.release.is_empty() && segments.prerelease.is_empty();
+
+            if !byte.is_ascii_alphanumeric() || (is_first_segment && !byte.is_ascii_digit()) {
+                // The first segment can only be digits, and a run of letters begins the prerelease
+                // even before it's been pushed
+                let part = match run_start {
+                    Some(start) if !bytes[start].is_ascii_digit() => PartType::Prerelease,
+                    _ => segments.part(),
+                };
+
+                return Err(Error::InvalidCharacter {
+                    part,
+                    byte: *byte,
+                    position,
+                });
+            }
+
+            if let Some(start) = run_start {
+                if bytes[start].is_ascii_digit() != byte.is_ascii_digit() {
+                    segments.push(&bytes[start..position])?;
+                    run_start = None;
+                }
+            }
+
+            run_start.get_or_insert(position);
+        }
+
+        if segment_start == bytes.len() {
+            return Err(Error::EmptyPart(segments.part()));
+        }
+
+        if let Some(start) = run_start {
+            segments.push(&bytes[start..])?;
+        }
+
+        let Segments {
+            release,
+            prerelease,
+        } = segments;
+
+        let mut version = Version::new(
+            release[0],
+            release.get(1).copied().unwrap_or(0),
+            release.get(2).copied().unwrap_or(0),
+            Some(prerelease).filter(|prerelease| !prerelease.is_empty()),
+            None,
+            None,
+            dialect,
+        );
+        version.extension = Some(Extension::RubyGems(Release {
+            specified_segments: release.len(),
+            extra_segments: release.iter().skip(3).copied().collect(),
+        }));
+
+        Ok(version)
+    }
+
+    /// Compare two versions segment-by-segment, following the RubyGems precedence rules.
+    ///
+    /// Missing segments are treated as zero (so `1.0` == `1.0.0`), numeric segments are compared
+    /// numerically and letter segments are compared lexically. Unlike SemVer, a letter segment always
+    /// sorts before a numeric segment, which is what makes a prerelease sort before its release.
+    ///
+    /// For example: `1.0.a` < `1.0.b1` < `1.0.pre` < `1.0` < `1.0.0.1` < `1.1`
+    fn cmp(a: &Version, b: &Version) -> Ordering {
+        let (a, b) = (segments(a), segments(b));
+
+        (0..a.len().max(b.len()))
+            .map(|i| compare_segment(a.get(i), b.get(i)))
+            .find(|ordering| ordering != &Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    }
+
+    /// Compare two versions and decide if they're considered equal, following the RubyGems precedence rules.
+    fn eq(a: &Version, b: &Version) -> bool {
+        RubyGems::cmp(a, b) == Ordering::Equal
+    }
+
+    /// Hash the segments of a version without any trailing zero segments, as missing segments are
+    /// treated as zero when comparing (so `1.pre` == `1.pre.0`).
+    fn hash<H: Hasher>(version: &Version, state: &mut H) {
+        canonical_segments(version).hash(state);
+    }
+
+    /// Normalize a version into its dot-separated segments, without any trailing zero segments (so
+    /// `1.pre.0` and `1.0-0` are both normalized to `1.pre`).
+    fn canonicalize(version: &Version) -> String {
+        let segments = canonical_segments(version);
+
+        if segments.is_empty() {
+            return String::from("0");
+        }
+
+        segments
+            .iter()
+            .map(|segment| segment.to_string())
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+
+    /// Format a version back into its dot-separated segments.
+    fn format(version: &Version) -> String {
+        segments(version)
+            .iter()
+            .map(|segment| segment.to_string())
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+}
+
+/// The segments read from a version string, split into the release segments and the prerelease.
+struct Segments {
+    release: Vec<usize>,
+    prerelease: Vec<PrereleaseComponent>,
+}
+
+impl Segments {
+    /// The part of the version that the next segment belongs to, used when reporting errors.
+    fn part(&self) -> PartType {
+        if !self.prerelease.is_empty() {
+            return PartType::Prerelease;
+        }
+
+        match self.release.len() {
+            0 => PartType::Major,
+            1 => PartType::Minor,
+            _ => PartType::Patch,
+        }
+    }
+
+    /// Add a run of either digits or letters as the next segment.
+    fn push(&mut self, run: &[u8]) -> Result<(), Error> {
+        let part = self.part();
+        let run = core::str::from_utf8(run).map_err(|_| Error::InvalidUtf8(part))?;
+
+        if !run.starts_with(|c: char| c.is_ascii_digit()) {
+            self.prerelease
+                .push(PrereleaseComponent::String(run.to_string()));
+
+            return Ok(());
+        }
+
+        let number = run.parse().map_err(|_| Error::NumberOverflow(part))?;
+
+        if self.prerelease.is_empty() {
+            self.release.push(number);
+        } else {
+            self.prerelease.push(PrereleaseComponent::Number(number));
+        }
+
+        Ok(())
+    }
+}
+
+/// Interpret every segment of a version, including any release segments beyond the patch version.
+///
+/// Versions which weren't parsed using the dialect (for example, when bumping a version) are
+/// interpreted from their standard parts alone.
+fn segments(version: &Version) -> Vec<PrereleaseComponent> {
+    let release = match &version.extension {
+        Some(Extension::RubyGems(release)) => release.clone(),
+        _ => Release {
+            specified_segments: 3,
+            extra_segments: vec![],
+        },
+    };
+
+    let mut segments = vec![version.major, version.minor, version.patch];
+    segments.extend(release.extra_segments);
+
+    // Only keep the trailing zero segments which were originally specified (like 1.0)
+    while segments.len() > release.specified_segments.max(1) && segments.last() == Some(&0) {
+        segments.pop();
+    }
+
+    let mut segments = segments
+        .into_iter()
+        .map(PrereleaseComponent::Number)
+        .collect::<Vec<_>>();
+
+    if let Prerelease::Identifier(components) = &version.prerelease {
+        segments.extend(components.iter().cloned());
+    }
+
+    segments
+}
+
+/// Every segment of a version, without any trailing zero segments, as missing segments are treated
+/// as zero when comparing.
+fn canonical_segments(version: &Version) -> Vec<PrereleaseComponent> {
+    let mut segments = segments(version);
+
+    while segments.last() == Some(&PrereleaseComponent::Number(0)) {
+        segments.pop();
+    }
+
+    segments
+}
+
+/// Compare a single segment, treating a missing segment as zero.
+fn compare_segment(a: Option<&PrereleaseComponent>, b: Option<&PrereleaseComponent>) -> Ordering {
+    let zero = PrereleaseComponent::Number(0);
+
+    match (a.unwrap_or(&zero), b.unwrap_or(&zero)) {
+        (PrereleaseComponent::Number(a), PrereleaseComponent::Number(b)) => a.cmp(b),
+        (PrereleaseComponent::String(a), PrereleaseComponent::String(b)) => a.cmp(b),
+        (PrereleaseComponent::String(_), PrereleaseComponent::Number(_)) => Ordering::Less,
+        (PrereleaseComponent::Number(_), PrereleaseComponent::String(_)) => Ordering::Greater,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::{Dialect, Error, PartType, PrereleaseComponent, Version};
+
+    #[test]
+    fn should_parse_any_number_of_segments() {
+        let version = Version::parse("1.2.3.4.5", Dialect::RubyGems).unwrap();
+
+        assert_eq!(version.core(), (1, 2, 3));
+        assert!(!version.is_prerelease());
+        assert_eq!(version.to_string(), "1.2.3.4.5");
+        assert_eq!(
+            Version::parse("1.2", Dialect::RubyGems)
+                .unwrap()
+                .to_string(),
+            "1.2"
+        );
+    }
+
+    #[test]
+    fn should_parse_letter_segment_as_prerelease() {
+        let version = Version::parse("1.0.0.pre.1", Dialect::RubyGems).unwrap();
+
+        assert_eq!(version.core(), (1, 0, 0));
+        assert_eq!(
+            version.prerelease.components(),
+            &[
+                PrereleaseComponent::String("pre".to_string()),
+                PrereleaseComponent::Number(1)
+            ]
+        );
+    }
+
+    #[test]
+    fn should_split_segments_between_letters_and_digits() {
+        let version = Version::parse("1.0a1", Dialect::RubyGems).unwrap();
+
+        assert_eq!(
+            version.prerelease.components(),
+            &[
+                PrereleaseComponent::String("a".to_string()),
+                PrereleaseComponent::Number(1)
+            ]
+        );
+        assert_eq!(version.to_string(), "1.0.a.1");
+    }
+
+    #[test]
+    fn should_normalize_hyphen_to_pre() {
+        let version = Version::parse("1.0.0-rc1", Dialect::RubyGems).unwrap();
+
+        assert_eq!(version.to_string(), "1.0.0.pre.rc.1");
+        assert_eq!(
+            version,
+            Version::parse("1.0.0.pre.rc.1", Dialect::RubyGems).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_compare_extra_segments() {
+        let a = Version::parse("1.2.3.4", Dialect::RubyGems).unwrap();
+        let b = Version::parse("1.2.3", Dialect::RubyGems).unwrap();
+
+        assert!(a > b);
+        assert!(a < Version::parse("1.2.4", Dialect::RubyGems).unwrap());
+        assert_eq!(b, Version::parse("1.2.3.0", Dialect::RubyGems).unwrap());
+    }
+
+    #[test]
+    fn should_order_prerelease_below_release() {
+        let prerelease = Version::parse("1.0.0.pre", Dialect::RubyGems).unwrap();
+        let release = Version::parse("1.0.0", Dialect::RubyGems).unwrap();
+
+        assert!(prerelease < release);
+        assert!(Version::parse("1.0.a", Dialect::RubyGems).unwrap() < prerelease);
+    }
+
+    #[test]
+    fn should_order_following_precedence() {
+        let versions = [
+            "1.0.a", "1.0.b1", "1.0.b2", "1.0.pre", "1.0", "1.0.0.1", "1.1", "2",
+        ];
+
+        for pair in versions.windows(2) {
+            let a = Version::parse(pair[0], Dialect::RubyGems).unwrap();
+            let b = Version::parse(pair[1], Dialect::RubyGems).unwrap();
+
+            assert!(a < b, "{} < {}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn should_fail_invalid_versions() {
+        for (version, error) in [
+            ("", Error::EmptyPart(PartType::Major)),
+            (
+                "a.1",
+                Error::InvalidCharacter {
+                    part: PartType::Major,
+                    byte: b'a',
+                    position: 0,
+                },
+            ),
+            (
+                "1a",
+                Error::InvalidCharacter {
+                    part: PartType::Major,
+                    byte: b'a',
+                    position: 1,
+                },
+            ),
+            ("1..2", Error::EmptyPart(PartType::Minor)),
+            ("1.2.", Error::EmptyPart(PartType::Patch)),
+            ("1.0-", Error::EmptyPart(PartType::Prerelease)),
+            (
+                "1.0.pre_1",
+                Error::InvalidCharacter {
+                    part: PartType::Prerelease,
+                    byte: b'_',
+                    position: 7,
+                },
+            ),
+            (
+                "1.0+build",
+                Error::InvalidCharacter {
+                    part: PartType::Minor,
+                    byte: b'+',
+                    position: 3,
+                },
+            ),
+        ] {
+            assert_eq!(
+                Version::parse(version, Dialect::RubyGems),
+                Err(error),
+                "{}",
+                version
+            );
+        }
+    }
+
+    #[test]
+    fn should_interpret_bumped_versions() {
+        let version = Version::parse("1.2.3.4.pre", Dialect::RubyGems).unwrap();
+
+        assert_eq!(version.bump_minor().to_string(), "1.3.0");
+        assert!(version < version.bump_patch());
+    }
+
+    #[test]
+    fn should_hash_equal_versions_equally() {
+        extern crate std;
+
+        use core::hash::BuildHasher;
+        use std::collections::hash_map::RandomState;
+
+        let state = RandomState::new();
+
+        for (a, b) in [
+            ("1-0", "1.pre"),
+            ("01-0", "1.pre"),
+            ("1.a.0.0", "1.a"),
+            ("1.0.0.0", "1"),
+            ("1.0.pre.0", "1.0-0"),
+        ] {
+            let (a, b) = (
+                Version::parse(a, Dialect::RubyGems).unwrap(),
+                Version::parse(b, Dialect::RubyGems).unwrap(),
+            );
+
+            assert_eq!(a, b);
+            assert_eq!(state.hash_one(&a), state.hash_one(&b), "{} and {}", a, b);
+        }
+    }
+}
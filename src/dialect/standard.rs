@@ -6,15 +6,28 @@ impl DialectParser for Standard {}
 
 #[cfg(test)]
 mod tests {
+    use core::cmp::Ordering;
+
     use super::*;
     use crate::component::PartType;
-    use crate::Error;
-    use alloc::vec;
+    use crate::{Dialect, Error, Version};
+
+    #[test]
+    fn should_parse_through_dialect() {
+        let version = Dialect::Standard.parse("1.2.3").unwrap();
+
+        assert_eq!(version.core(), (1, 2, 3));
+        assert_eq!(version.dialect(), Dialect::Standard);
+        assert_eq!(version, Version::parse("1.2.3", Dialect::Standard).unwrap());
+        assert_eq!(
+            Dialect::Standard.parse("1.2.x"),
+            Version::parse("1.2.x", Dialect::Standard)
+        );
+    }
 
     #[test]
     fn should_move_to_minor_from_major() {
-        let result =
-            Standard::parse_byte(&b'.', (PartType::Major, &vec![b'1']), &[b'1', b'2']).unwrap();
+        let result = Standard::parse_byte(&b'.', 0, (PartType::Major, b"1"), b"12").unwrap();
 
         let next_type = result;
 
@@ -23,8 +36,7 @@ mod tests {
 
     #[test]
     fn should_move_to_patch_from_minor() {
-        let result =
-            Standard::parse_byte(&b'.', (PartType::Minor, &vec![b'1', b'1']), &[b'0']).unwrap();
+        let result = Standard::parse_byte(&b'.', 0, (PartType::Minor, b"11"), b"0").unwrap();
 
         let next_type = result;
 
@@ -33,7 +45,7 @@ mod tests {
 
     #[test]
     fn should_move_to_prerelease_from_patch() {
-        let result = Standard::parse_byte(&b'-', (PartType::Patch, &vec![b'0']), &[b'a']).unwrap();
+        let result = Standard::parse_byte(&b'-', 0, (PartType::Patch, b"0"), b"a").unwrap();
 
         let next_type = result;
 
@@ -42,7 +54,7 @@ mod tests {
 
     #[test]
     fn should_move_to_build_from_patch() {
-        let result = Standard::parse_byte(&b'+', (PartType::Patch, &vec![b'0']), &[b'a']).unwrap();
+        let result = Standard::parse_byte(&b'+', 0, (PartType::Patch, b"0"), b"a").unwrap();
 
         let next_type = result;
 
@@ -51,29 +63,147 @@ mod tests {
 
     #[test]
     fn should_fail_non_numerics_in_major() {
-        let result = Standard::parse_byte(&b'a', (PartType::Major, &vec![b'1']), &[b'1', b'2']);
-
-        assert_eq!(Err(Error::InvalidCharacter(PartType::Major)), result);
+        let result = Standard::parse_byte(&b'a', 0, (PartType::Major, b"1"), b"12");
+
+        assert_eq!(
+            Err(Error::InvalidCharacter {
+                part: PartType::Major,
+                byte: b'a',
+                position: 0
+            }),
+            result
+        );
     }
 
     #[test]
     fn should_fail_non_numerics_in_minor() {
-        let result = Standard::parse_byte(&b'a', (PartType::Minor, &vec![]), &[b'1', b'2']);
-
-        assert_eq!(Err(Error::InvalidCharacter(PartType::Minor)), result);
+        let result = Standard::parse_byte(&b'a', 0, (PartType::Minor, b""), b"12");
+
+        assert_eq!(
+            Err(Error::InvalidCharacter {
+                part: PartType::Minor,
+                byte: b'a',
+                position: 0
+            }),
+            result
+        );
     }
 
     #[test]
     fn should_fail_using_dot_after_patch() {
-        let result = Standard::parse_byte(&b'.', (PartType::Patch, &vec![b'9']), &[b'1', b'2']);
-
-        assert_eq!(Err(Error::InvalidCharacter(PartType::Patch)), result);
+        let result = Standard::parse_byte(&b'.', 0, (PartType::Patch, b"9"), b"12");
+
+        assert_eq!(
+            Err(Error::InvalidCharacter {
+                part: PartType::Patch,
+                byte: b'.',
+                position: 0
+            }),
+            result
+        );
     }
 
     #[test]
     fn should_fail_non_numerics_in_patch() {
-        let result = Standard::parse_byte(&b'a', (PartType::Patch, &vec![b'9']), &[b'1', b'2']);
+        let result = Standard::parse_byte(&b'a', 0, (PartType::Patch, b"9"), b"12");
+
+        assert_eq!(
+            Err(Error::InvalidCharacter {
+                part: PartType::Patch,
+                byte: b'a',
+                position: 0
+            }),
+            result
+        );
+    }
+
+    #[test]
+    fn should_compare_numeric_prerelease_numerically() {
+        let two = Version::parse("1.0.0-2", Dialect::Standard).unwrap();
+        let ten = Version::parse("1.0.0-10", Dialect::Standard).unwrap();
+
+        assert!(two < ten);
+        assert!(
+            Version::parse("1.0.0-alpha.2", Dialect::Standard).unwrap()
+                < Version::parse("1.0.0-alpha.10", Dialect::Standard).unwrap()
+        );
+    }
 
-        assert_eq!(Err(Error::InvalidCharacter(PartType::Patch)), result);
+    #[test]
+    fn should_order_numeric_prerelease_below_alphanumeric() {
+        let numeric = Version::parse("1.0.0-2", Dialect::Standard).unwrap();
+        let alphanumeric = Version::parse("1.0.0-alpha", Dialect::Standard).unwrap();
+
+        assert!(numeric < alphanumeric);
+        assert!(
+            Version::parse("1.0.0-alpha.1", Dialect::Standard).unwrap()
+                < Version::parse("1.0.0-alpha.beta", Dialect::Standard).unwrap()
+        );
+        assert!(
+            Version::parse("1.0.0-999", Dialect::Standard).unwrap()
+                < Version::parse("1.0.0-1a", Dialect::Standard).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_compare_alphanumeric_prerelease_lexically() {
+        let versions = [
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1.0.0-rc.1",
+            "1.0.0",
+        ];
+
+        for pair in versions.windows(2) {
+            let a = Version::parse(pair[0], Dialect::Standard).unwrap();
+            let b = Version::parse(pair[1], Dialect::Standard).unwrap();
+
+            assert!(a < b, "{} < {}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn should_compare_when_only_minor_differs() {
+        let lower = Version::parse("1.2.3", Dialect::Standard).unwrap();
+        let higher = Version::parse("1.3.3", Dialect::Standard).unwrap();
+
+        assert_eq!(lower.cmp(&higher), Ordering::Less);
+        assert_eq!(higher.cmp(&lower), Ordering::Greater);
+        assert_eq!(lower.cmp(&lower.clone()), Ordering::Equal);
+    }
+
+    #[test]
+    fn should_compare_each_tie_break_level() {
+        for (lower, higher) in [
+            ("1.9.9", "2.0.0"),
+            ("1.1.9", "1.2.0"),
+            ("1.1.1", "1.1.2"),
+            ("1.1.1-alpha", "1.1.1-beta"),
+            ("1.1.1-alpha", "1.1.1-alpha.1"),
+            ("1.1.1-rc.1", "1.1.1"),
+        ] {
+            let lower = Version::parse(lower, Dialect::Standard).unwrap();
+            let higher = Version::parse(higher, Dialect::Standard).unwrap();
+
+            assert_eq!(lower.cmp(&higher), Ordering::Less, "{} < {}", lower, higher);
+            assert_eq!(
+                higher.cmp(&lower),
+                Ordering::Greater,
+                "{} > {}",
+                higher,
+                lower
+            );
+        }
+
+        assert_eq!(
+            Version::parse("1.1.1-rc.1", Dialect::Standard)
+                .unwrap()
+                .cmp(&Version::parse("1.1.1-rc.1", Dialect::Standard).unwrap()),
+            Ordering::Equal
+        );
     }
 }
@@ -0,0 +1,447 @@
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::dialect::Dialect;
+use crate::error::Error;
+use crate::{BuildMetadata, PartType, Prerelease, PrereleaseComponent, Version};
+
+/// The dialects which can be encoded, indexed by their encoded tag.
+const DIALECTS: [Dialect; 12] = [
+    Dialect::Standard,
+    Dialect::Cargo,
+    Dialect::Npm,
+    Dialect::Pep440,
+    Dialect::Go,
+    Dialect::Debian,
+    Dialect::RubyGems,
+    Dialect::NuGet,
+    Dialect::Lenient,
+    Dialect::Composer,
+    Dialect::DockerTag,
+    Dialect::Hex,
+];
+
+impl Version {
+    /// Encode the version into a compact, deterministic binary representation.
+    ///
+    /// The layout is:
+    /// 1. The dialect, as a single byte.
+    /// 2. The prefix (like `v`), as a single byte, or zero when there is no prefix.
+    /// 3. The major, minor and patch versions, each as an unsigned little-endian base 128 varint.
+    /// 4. The prerelease identifier, as a varint length followed by its UTF-8 bytes (a length of
+    ///    zero means there is no prerelease).
+    /// 5. The build metadata, encoded the same as the prerelease identifier.
+    /// 6. The parts specific to the version's dialect (like a PEP 440 epoch, or a NuGet revision), as
+    ///    the formatted version, encoded the same as the prerelease identifier (a length of zero means
+    ///    there are no dialect-specific parts).
+    ///
+    /// Dialect-specific parts are recovered by parsing the formatted version, so every part of the
+    /// version is preserved.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let version = Version::parse("1.2.3-alpha.1+build", Dialect::Standard)?;
+    /// assert_eq!(Version::decode(&version.encode())?.to_string(), "1.2.3-alpha.1+build");
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![
+            self.dialect as u8,
+            self.prefix.map_or(0, |prefix| prefix as u8),
+        ];
+
+        for number in [self.major, self.minor, self.patch] {
+            encode_varint(&mut bytes, number);
+        }
+
+        let prerelease = match &self.prerelease {
+            Prerelease::Empty => String::new(),
+            Prerelease::Identifier(components) => components
+                .iter()
+                .map(|component| component.to_string())
+                .collect::<Vec<_>>()
+                .join("."),
+        };
+
+        let build_metadata = match &self.build_metadata {
+            BuildMetadata::Empty => "",
+            BuildMetadata::Identifier(identifier) => identifier.as_str(),
+        };
+
+        let extension = match &self.extension {
+            Some(_) => self.to_string(),
+            None => String::new(),
+        };
+
+        for string in [prerelease.as_str(), build_metadata, extension.as_str()] {
+            encode_varint(&mut bytes, string.len());
+            bytes.extend_from_slice(string.as_bytes());
+        }
+
+        bytes
+    }
+
+    /// Decode a version from the binary representation produced by [`Version::encode`].
+    ///
+    /// Malformed bytes return an [`Error::InvalidEncoding`] error, and the prefix, prerelease
+    /// identifier and build metadata are validated against the encoded dialect. Any dialect-specific
+    /// parts are parsed following the encoded dialect, and must agree with the standard parts.
+    ///
+    /// The decoded parts must form a version which parses the same in the encoded dialect (so a Go
+    /// version must have its `v` prefix), otherwise an [`Error::InvalidEncoding`] error is returned
+    /// for the dialect, at position zero.
+    pub fn decode(bytes: &[u8]) -> Result<Version, Error> {
+        let mut decoder = Decoder { bytes, position: 0 };
+
+        let dialect = *DIALECTS
+            .get(usize::from(decoder.byte()?))
+            .ok_or(decoder.error(1))?;
+
+        let prefix = match decoder.byte()? {
+            0 => None,
+            // The prefix is checked ahead of a major version, as some dialects only accept a prefix
+            // which is followed by one
+            prefix => match dialect.parse_prefix(&[prefix, b'0']) {
+                (Some(prefix), [b'0']) => Some(prefix),
+                _ => return Err(decoder.error(1)),
+            },
+        };
+
+        let major = decoder.varint()?;
+        let minor = decoder.varint()?;
+        let patch = decoder.varint()?;
+
+        let prerelease = decoder.string()?;
+        let build_metadata = decoder.string()?;
+        let extension_position = decoder.position;
+        let extension = decoder.string()?;
+
+        if decoder.position != bytes.len() {
+            // Any trailing bytes mean the encoding is malformed
+            return Err(decoder.error(0));
+        }
+
+        let prerelease = match prerelease {
+            "" => None,
+            prerelease => Some(
+                prerelease
+                    .split('.')
+                    .map(|component| decode_prerelease_component(component, dialect))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+        };
+
+        let build_metadata = match build_metadata {
+            "" => None,
+            build_metadata => {
+                Version::validate_part(
+                    build_metadata.as_bytes(),
+                    PartType::BuildMetadata,
+                    dialect,
+                )?;
+
+                Some(build_metadata.to_string())
+            }
+        };
+
+        let version = Version::new(
+            major,
+            minor,
+            patch,
+            prerelease,
+            build_metadata,
+            prefix,
+            dialect,
+        );
+
+        if extension.is_empty() {
+            // Parsing the formatted version checks that the parts are valid together in the dialect
+            // (like a required prefix), and rebuilds any dialect-specific parts the dialect needs
+            return match Version::parse(&version.to_string(), dialect) {
+                Ok(parsed) if has_same_parts(&parsed, &version) => Ok(parsed),
+                _ => Err(Error::InvalidEncoding { position: 0 }),
+            };
+        }
+
+        let parsed = Version::parse(extension, dialect)?;
+
+        if parsed.extension.is_none() || !has_same_parts(&parsed, &version) {
+            // The dialect-specific parts must describe the same version as the standard parts
+            return Err(Error::InvalidEncoding {
+                position: extension_position,
+            });
+        }
+
+        Ok(parsed)
+    }
+}
+
+/// Whether two versions have the same standard parts and prefix.
+fn has_same_parts(a: &Version, b: &Version) -> bool {
+    (a.major, a.minor, a.patch, a.prefix) == (b.major, b.minor, b.patch, b.prefix)
+        && a.prerelease == b.prerelease
+        && a.build_metadata == b.build_metadata
+}
+
+/// Append a number as an unsigned little-endian base 128 varint, where each byte holds seven bits of
+/// the number and the high bit marks that more bytes follow.
+fn encode_varint(bytes: &mut Vec<u8>, mut number: usize) {
+    loop {
+        let byte = (number & 0x7f) as u8;
+        number >>= 7;
+
+        if number == 0 {
+            bytes.push(byte);
+            return;
+        }
+
+        bytes.push(byte | 0x80);
+    }
+}
+
+/// Interpret a single prerelease component, validating it against the dialect.
+fn decode_prerelease_component(
+    component: &str,
+    dialect: Dialect,
+) -> Result<PrereleaseComponent, Error> {
+    Version::validate_part(component.as_bytes(), PartType::Prerelease, dialect)?;
+
+    PrereleaseComponent::new(component)
+}
+
+/// A simple cursor over encoded bytes.
+struct Decoder<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Decoder<'a> {
+    /// An error pointing at a byte which has already been read, counting backwards from the current
+    /// position.
+    fn error(&self, rewind: usize) -> Error {
+        Error::InvalidEncoding {
+            position: self.position - rewind,
+        }
+    }
+
+    /// Read a single byte.
+    fn byte(&mut self) -> Result<u8, Error> {
+        let byte = *self.bytes.get(self.position).ok_or(self.error(0))?;
+        self.position += 1;
+
+        Ok(byte)
+    }
+
+    /// Read an unsigned little-endian base 128 varint.
+    fn varint(&mut self) -> Result<usize, Error> {
+        let mut number: usize = 0;
+        let mut shift = 0;
+
+        loop {
+            let byte = self.byte()?;
+            let bits = usize::from(byte & 0x7f);
+
+            if shift >= usize::BITS || (bits << shift) >> shift != bits {
+                // The number doesn't fit in a usize
+                return Err(self.error(1));
+            }
+
+            number |= bits << shift;
+            shift += 7;
+
+            if byte & 0x80 == 0 {
+                return Ok(number);
+            }
+        }
+    }
+
+    /// Read a varint length, followed by that many bytes of UTF-8.
+    fn string(&mut self) -> Result<&'a str, Error> {
+        let start = self.position;
+        let length = self.varint()?;
+
+        let end = self
+            .position
+            .checked_add(length)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or(Error::InvalidEncoding { position: start })?;
+
+        let string = core::str::from_utf8(&self.bytes[self.position..end])
+            .map_err(|_| Error::InvalidEncoding { position: start })?;
+        self.position = end;
+
+        Ok(string)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    use proptest::prelude::*;
+
+    use crate::encoding::DIALECTS;
+    use crate::{Dialect, Error, PartType, Version};
+
+    #[test]
+    fn should_tag_dialects_by_declaration_order() {
+        for (tag, dialect) in DIALECTS.iter().enumerate() {
+            assert_eq!(*dialect as usize, tag);
+        }
+    }
+
+    #[test]
+    fn should_round_trip_version() {
+        for (version, dialect) in [
+            ("1.2.3-alpha.1+build.5", Dialect::Standard),
+            ("0.0.0", Dialect::Standard),
+            ("v300.70000.9000000-rc.10", Dialect::Npm),
+            ("v2.0.0+incompatible", Dialect::Go),
+        ] {
+            let version = Version::parse(version, dialect).unwrap();
+            let decoded = Version::decode(&version.encode()).unwrap();
+
+            assert_eq!(decoded, version);
+            assert_eq!(decoded.to_string(), version.to_string());
+            assert_eq!(decoded.build_metadata, version.build_metadata);
+            assert_eq!(decoded.dialect(), version.dialect());
+        }
+    }
+
+    #[test]
+    fn should_round_trip_dialect_specific_parts() {
+        for (version, dialect) in [
+            ("2!1.0", Dialect::Pep440),
+            ("1.0.post1", Dialect::Pep440),
+            ("092", Dialect::Pep440),
+            ("1!1.2.3.4rc1.post2.dev3+local", Dialect::Pep440),
+            ("20110~rc", Dialect::Debian),
+            ("1:1.2~rc1-3", Dialect::Debian),
+            ("b", Dialect::DockerTag),
+            ("v1.21-alpine", Dialect::DockerTag),
+            ("1.2.3.4", Dialect::NuGet),
+            ("1.2.3.4-beta+build", Dialect::NuGet),
+            ("1.2.3.4", Dialect::RubyGems),
+            ("1.0-rc1", Dialect::RubyGems),
+            ("1.0.0.1-patch2", Dialect::Composer),
+        ] {
+            let version = Version::parse(version, dialect).unwrap();
+            let decoded = Version::decode(&version.encode()).unwrap();
+
+            assert_eq!(decoded, version);
+            assert_eq!(decoded.to_string(), version.to_string());
+            assert_eq!(decoded.extension, version.extension);
+        }
+    }
+
+    #[test]
+    fn should_round_trip_maximum_numbers() {
+        let version = Version::builder()
+            .major(usize::MAX)
+            .minor(usize::MAX)
+            .patch(usize::MAX)
+            .build()
+            .unwrap();
+
+        assert_eq!(Version::decode(&version.encode()).unwrap(), version);
+    }
+
+    #[test]
+    fn should_encode_deterministic_layout() {
+        let version = Version::parse("1.200.3-a+b", Dialect::Cargo).unwrap();
+
+        assert_eq!(
+            version.encode(),
+            vec![1, 0, 1, 0xc8, 0x01, 3, 1, b'a', 1, b'b', 0]
+        );
+    }
+
+    #[test]
+    fn should_fail_malformed_bytes() {
+        for (bytes, error) in [
+            (vec![], Error::InvalidEncoding { position: 0 }),
+            (vec![200], Error::InvalidEncoding { position: 0 }),
+            (vec![0, b'!'], Error::InvalidEncoding { position: 1 }),
+            (
+                vec![0, b'v', 1, 2, 3, 0, 0, 0],
+                Error::InvalidEncoding { position: 1 },
+            ),
+            (
+                vec![4, b'V', 1, 2, 3, 0, 0, 0],
+                Error::InvalidEncoding { position: 1 },
+            ),
+            (
+                vec![4, 0, 1, 2, 3, 0, 0, 0],
+                Error::InvalidEncoding { position: 0 },
+            ),
+            (
+                vec![3, 0, 1, 2, 3, 1, b'a', 0, 0],
+                Error::InvalidEncoding { position: 0 },
+            ),
+            (vec![0, 0, 1, 2], Error::InvalidEncoding { position: 4 }),
+            (vec![0, 0, 0x80], Error::InvalidEncoding { position: 3 }),
+            (
+                vec![
+                    0, 0, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01,
+                ],
+                Error::InvalidEncoding { position: 11 },
+            ),
+            (
+                vec![0, 0, 1, 2, 3, 5, b'a'],
+                Error::InvalidEncoding { position: 5 },
+            ),
+            (
+                vec![0, 0, 1, 2, 3, 1, 0xff, 0],
+                Error::InvalidEncoding { position: 5 },
+            ),
+            (
+                vec![0, 0, 1, 2, 3, 0, 0, 0, 0],
+                Error::InvalidEncoding { position: 8 },
+            ),
+            (
+                vec![0, 0, 1, 2, 3, 1, b'$', 0, 0],
+                Error::InvalidCharacter {
+                    part: PartType::Prerelease,
+                    byte: b'$',
+                    position: 0,
+                },
+            ),
+            (
+                vec![0, 0, 1, 2, 3, 2, b'a', b'.', 0, 0],
+                Error::EmptyPart(PartType::Prerelease),
+            ),
+            (
+                vec![3, 0, 5, 0, 0, 0, 0, 3, b'2', b'!', b'1'],
+                Error::InvalidEncoding { position: 7 },
+            ),
+            (
+                vec![3, 0, 1, 0, 0, 0, 0, 3, b'2', b'!', b'x'],
+                Error::InvalidCharacter {
+                    part: PartType::Major,
+                    byte: b'x',
+                    position: 2,
+                },
+            ),
+        ] {
+            assert_eq!(Version::decode(&bytes), Err(error), "{:?}", bytes);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips_every_dialect(version in "v?[0-9a-z.+~:!_-]{1,16}") {
+            for dialect in DIALECTS {
+                if let Ok(version) = Version::parse(&version, dialect) {
+                    let decoded = Version::decode(&version.encode()).unwrap();
+
+                    assert_eq!(&decoded, &version);
+                    assert_eq!(decoded.to_string(), version.to_string());
+                    assert_eq!(decoded.build_metadata, version.build_metadata);
+                    assert_eq!(decoded.extension, version.extension);
+                }
+            }
+        }
+    }
+}
@@ -1,12 +1,198 @@
+use core::fmt::{Display, Formatter};
+
 use crate::component::PartType;
 
 /// Error parsing a version string.
 #[derive(Debug, PartialEq)]
 pub enum Error {
     /// A part of the provided version string contains an invalid character.
-    InvalidCharacter(PartType),
+    ///
+    /// The byte is the rejected character (or, for multi-byte characters, its first byte), and the
+    /// position is its byte offset, from the start of the version string.
+    InvalidCharacter {
+        part: PartType,
+        byte: u8,
+        position: usize,
+    },
 
     /// A part of the provided version string includes a preceding zero, which is not
     /// allowed.
     InvalidPrecedingZero(PartType),
+
+    /// A part of the provided version string is not valid UTF-8.
+    InvalidUtf8(PartType),
+
+    /// A numeric part of the provided version string is too large to be represented.
+    NumberOverflow(PartType),
+
+    /// A part of the provided version string was started, but is empty.
+    EmptyPart(PartType),
+
+    /// A numeric part of the provided version string was omitted, when parsing strictly requires every
+    /// numeric part (like the patch version in `1.2`).
+    MissingPart(PartType),
+
+    /// A part of the provided requirement contains a wildcard in a position where it's not allowed
+    /// (like `1.*.3`).
+    InvalidWildcard(PartType),
+
+    /// The bytes provided when decoding a version are not a valid encoding (like being truncated).
+    ///
+    /// The position is the byte offset where the invalid encoding was found.
+    InvalidEncoding { position: usize },
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::InvalidCharacter {
+                part,
+                byte,
+                position,
+            } => write!(
+                f,
+                "{} contains an invalid character '{}' at byte {}",
+                Part(part),
+                core::ascii::escape_default(*byte),
+                position
+            ),
+            Error::InvalidPrecedingZero(part) => {
+                write!(f, "{} contains an invalid leading zero", Part(part))
+            }
+            Error::InvalidUtf8(part) => write!(f, "{} is not valid UTF-8", Part(part)),
+            Error::NumberOverflow(part) => {
+                write!(f, "{} is too large to be represented", Part(part))
+            }
+            Error::EmptyPart(part) => write!(f, "{} is empty", Part(part)),
+            Error::MissingPart(part) => write!(f, "{} is missing", Part(part)),
+            Error::InvalidWildcard(part) => {
+                write!(f, "{} contains a wildcard which is not allowed", Part(part))
+            }
+            Error::InvalidEncoding { position } => {
+                write!(f, "the encoded version is invalid at byte {}", position)
+            }
+        }
+    }
+}
+
+/// Describe a part of a version string when it's the subject of an error message (like "the minor
+/// version part").
+struct Part<'a>(&'a PartType);
+
+impl Display for Part<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self.0 {
+            PartType::Major | PartType::Minor | PartType::Patch => {
+                write!(f, "the {} version part", self.0)
+            }
+            PartType::Prerelease | PartType::BuildMetadata => write!(f, "the {}", self.0),
+        }
+    }
+}
+
+/// Errors implement [`core::error::Error`], which is the same trait as `std::error::Error`, so they
+/// can be returned with `?` from functions returning `Box<dyn std::error::Error>`.
+impl core::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::{Dialect, Error, PartType, Version};
+
+    #[test]
+    fn should_format_as_error_trait_object() {
+        let error = Version::parse("1.2.x", Dialect::Standard).unwrap_err();
+        let error: &dyn core::error::Error = &error;
+
+        assert_eq!(
+            error.to_string(),
+            "the patch version part contains an invalid character 'x' at byte 4"
+        );
+        assert!(error.source().is_none());
+    }
+
+    #[test]
+    fn should_box_as_error_trait_object() {
+        let error: alloc::boxed::Box<dyn core::error::Error> =
+            alloc::boxed::Box::new(Error::EmptyPart(PartType::Minor));
+
+        assert_eq!(error.to_string(), "the minor version part is empty");
+    }
+
+    #[test]
+    fn should_escape_non_printable_bytes() {
+        let error = Version::parse_bytes(b"1.2.3-\x01", Dialect::Standard).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "the prerelease contains an invalid character '\\x01' at byte 6"
+        );
+    }
+
+    #[test]
+    fn should_format_each_variant() {
+        for (error, message) in [
+            (
+                Error::InvalidCharacter {
+                    part: PartType::Major,
+                    byte: b't',
+                    position: 0,
+                },
+                "the major version part contains an invalid character 't' at byte 0",
+            ),
+            (
+                Error::InvalidPrecedingZero(PartType::Minor),
+                "the minor version part contains an invalid leading zero",
+            ),
+            (
+                Error::InvalidUtf8(PartType::Prerelease),
+                "the prerelease is not valid UTF-8",
+            ),
+            (
+                Error::NumberOverflow(PartType::Patch),
+                "the patch version part is too large to be represented",
+            ),
+            (
+                Error::EmptyPart(PartType::BuildMetadata),
+                "the build metadata is empty",
+            ),
+            (
+                Error::MissingPart(PartType::Patch),
+                "the patch version part is missing",
+            ),
+            (
+                Error::InvalidWildcard(PartType::Minor),
+                "the minor version part contains a wildcard which is not allowed",
+            ),
+            (
+                Error::InvalidEncoding { position: 3 },
+                "the encoded version is invalid at byte 3",
+            ),
+        ] {
+            assert_eq!(error.to_string(), message);
+        }
+    }
+
+    #[test]
+    fn should_convert_into_boxed_std_error() {
+        extern crate std;
+
+        use std::boxed::Box;
+
+        fn parse(version: &str) -> Result<(), Box<dyn std::error::Error>> {
+            Version::parse(version, Dialect::Standard)?;
+
+            Ok(())
+        }
+
+        assert!(parse("1.2.3").is_ok());
+
+        let error = parse("1.2.x").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "the patch version part contains an invalid character 'x' at byte 4"
+        );
+        assert!(error.source().is_none());
+    }
 }
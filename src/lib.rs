@@ -15,6 +15,13 @@
 //! smvr = "0.1.3"
 //! ```
 //!
+//! ### Features
+//!
+//! Feature | Description
+//! -|-
+//! `semver-compat` | Implements conversions between `smvr::Version` and the [`semver`](https://crates.io/crates/semver) crate's `Version`. Converted versions follow the standard dialect.
+//! `serde` | Implements `Serialize` and `Deserialize` for `smvr::Version`, using its string representation. Versions are deserialized using the standard dialect, or a specific dialect using `smvr::serde::dialect`.
+//!
 //! ## Dialects
 //!
 //! Dialects reflect interpretations of the SemVer specification.
@@ -23,11 +30,20 @@
 //! rules. For example, differing package managers may impose different constraints to the style of a SemVer string. This is
 //! the perfect use case for a dedicated dialect.
 //!
-//! Currently only Semver Versioning 2.0.0 is supported.
-//!
 //! Dialect | Description
 //! -|-
 //! `smvr::Dialect::Standard` | Follows the [Semantic Versioning 2.0.0](https://semver.org/spec/v2.0.0.html) specification.
+//! `smvr::Dialect::Cargo` | Follows the ordering used by [Cargo](https://doc.rust-lang.org/cargo/reference/resolver.html), where build metadata breaks ties between versions of equal precedence.
+//! `smvr::Dialect::Npm` | Follows the standard dialect, but tolerates a single leading `v` or `V` prefix (like `v1.2.3`), which is preserved when formatting.
+//! `smvr::Dialect::Pep440` | Follows the [PEP 440](https://peps.python.org/pep-0440/) specification used by Python packaging, including epochs, post releases, development releases and local versions.
+//! `smvr::Dialect::Go` | Follows the conventions used by [Go modules](https://go.dev/ref/mod#versions), requiring a leading `v` prefix (like `v1.2.3`) and recognising `+incompatible` build metadata on major versions v2 and above.
+//! `smvr::Dialect::Debian` | Follows the [Debian version format](https://www.debian.org/doc/debian-policy/ch-controlfields.html#version) (`[epoch:]upstream[-revision]`), comparing versions following the algorithm used by `dpkg`.
+//! `smvr::Dialect::RubyGems` | Follows the [RubyGems version format](https://guides.rubygems.org/patterns/#semantic-versioning) used by Ruby packaging, permitting any number of segments and treating letter segments as prereleases.
+//! `smvr::Dialect::NuGet` | Follows the [NuGet version format](https://learn.microsoft.com/en-us/nuget/concepts/package-versioning) used by .NET packaging, permitting a fourth numeric revision part after the patch version.
+//! `smvr::Dialect::Lenient` | Follows the standard dialect, but accepts (and normalizes away) leading zeros in the major, minor and patch versions (like `1.01.0`).
+//! `smvr::Dialect::Composer` | Follows the [Composer version format](https://getcomposer.org/doc/articles/versions.md) used by PHP packaging, ordering versions by their stability (`dev`, `alpha`, `beta`, `RC`, stable and `patch`).
+//! `smvr::Dialect::DockerTag` | Parses [container image tags](https://docs.docker.com/reference/cli/docker/image/tag/) which may not follow SemVer, keeping any suffix (like `-alpine`) as a tie-break, and ordering named tags (like `latest`) above every version.
+//! `smvr::Dialect::Hex` | Follows the conventions used by [Hex](https://hexdocs.pm/elixir/Version.html) for Elixir and Erlang packages, where requirements support the pessimistic `~>` operator (like `~> 2.1.2`).
 //!
 //! ## Parsing version strings
 //!
@@ -89,16 +105,40 @@
 //! # Ok::<(), Error>(())
 //! ```
 //!
+//! ## Matching requirements
+//!
+//! Requirements constrain which versions are acceptable, using a comma separated list of comparators (like `>=1.2.0, <2.0.0`).
+//!
+//! A version matches a requirement when it satisfies every comparator. Prerelease versions only match when a comparator
+//! explicitly opts in to prereleases for the same major, minor and patch version.
+//!
+//! Alternative requirements can be separated with `||` (like `^1.0.0 || ^2.0.0`) using `smvr::Requirement::parse_set`, which
+//! matches a version when any of the requirements match.
+//!
+//! ```rust
+//! use smvr::{Dialect, Requirement, Version};
+//! use smvr::Error;
+//!
+//! let requirement = Requirement::parse(">=1.2.0, <2.0.0", Dialect::Standard)?;
+//!
+//! assert!(requirement.matches(&Version::parse("1.5.0", Dialect::Standard)?));
+//! assert!(!requirement.matches(&Version::parse("2.0.0", Dialect::Standard)?));
+//! assert!(!requirement.matches(&Version::parse("1.5.0-alpha", Dialect::Standard)?));
+//! # Ok::<(), Error>(())
+//! ```
+//!
 //! ## Handling errors
 //!
 //! While parsing, each byte is be read, and if any bytes are encountered which do not conform with the rules implemented by
 //! the dialect, an error will be returned.
 //!
 //! These errors indicate, at a high level, what the error was caused by (an invalid character, for example) and where
-//! the error occurred (i.e. inside one of the parts: Major, Minor, Patch, Prerelease, Build Metadata).
+//! the error occurred (i.e. inside one of the parts: Major, Minor, Patch, Prerelease, Build Metadata). Invalid characters
+//! also report the byte offset they were found at.
 //!
 //! Errors are eagerly returned, which means **the first** invalid byte encountered will trigger an error. This does not guarantee there are no more
-//! violations in the rest of the version string.
+//! violations in the rest of the version string. When every violation is needed (for example, in an editor), `smvr::Version::parse_collecting`
+//! continues past each error and returns all of them, in the order they occur.
 //!
 //! ```rust
 //! use smvr::{Dialect, PartType, Version};
@@ -115,19 +155,38 @@
 //! }
 //!
 //! if let Err(error) = invalid_character_version {
-//!     assert_eq!(error, Error::InvalidCharacter(PartType::Major))
+//!     assert_eq!(error, Error::InvalidCharacter { part: PartType::Major, byte: b'a', position: 0 })
 //! }
 //! # Ok::<(), Error>(())
 //! ```
 
 extern crate alloc;
 
+pub(crate) mod builder;
+pub(crate) mod bump;
 pub(crate) mod component;
 pub(crate) mod dialect;
+pub(crate) mod encoding;
 pub(crate) mod error;
+pub(crate) mod partial;
+pub(crate) mod requirement;
+pub(crate) mod select;
+#[cfg(feature = "semver-compat")]
+mod semver;
+#[cfg(feature = "serde")]
+pub mod serde;
 pub(crate) mod version;
+pub(crate) mod version_core;
+pub(crate) mod version_parts;
 
+pub use builder::VersionBuilder;
+pub use bump::{BumpOptions, Increment};
 pub use component::*;
 pub use dialect::Dialect;
 pub use error::Error;
+pub use partial::*;
+pub use requirement::*;
+pub use select::*;
 pub use version::*;
+pub use version_core::*;
+pub use version_parts::*;
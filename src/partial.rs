@@ -0,0 +1,98 @@
+use crate::Version;
+
+/// A version which was parsed from a version string that may omit its minor and patch parts
+/// (like `1` or `1.2`).
+///
+/// Omitted parts default to zero in the version, while [`PartSpecificity`] records which parts were
+/// actually specified. This distinguishes versions which are otherwise identical, like `1.2` and `1.2.0`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartialVersion {
+    pub version: Version,
+    pub specified: PartSpecificity,
+}
+
+/// The most specific numeric part which was specified in a version string.
+///
+/// Specificities are ordered from least specific (`Major`) to most specific (`Patch`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PartSpecificity {
+    /// Only the major version was specified (like `1`).
+    Major,
+    /// The major and minor versions were specified (like `1.2`).
+    Minor,
+    /// The major, minor and patch versions were all specified (like `1.2.3`).
+    Patch,
+}
+
+impl PartSpecificity {
+    /// The specificity of a version string with the given number of numeric parts.
+    pub(crate) fn from_parts(parts: usize) -> PartSpecificity {
+        match parts {
+            0 | 1 => PartSpecificity::Major,
+            2 => PartSpecificity::Minor,
+            _ => PartSpecificity::Patch,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Dialect, PartSpecificity, Version};
+
+    #[test]
+    fn should_record_major_specificity() {
+        let partial = Version::parse_partial("1", Dialect::Standard).unwrap();
+
+        assert_eq!(partial.version.core(), (1, 0, 0));
+        assert_eq!(partial.specified, PartSpecificity::Major);
+    }
+
+    #[test]
+    fn should_record_minor_specificity() {
+        let partial = Version::parse_partial("1.2", Dialect::Standard).unwrap();
+
+        assert_eq!(partial.version.core(), (1, 2, 0));
+        assert_eq!(partial.specified, PartSpecificity::Minor);
+    }
+
+    #[test]
+    fn should_record_patch_specificity() {
+        let partial = Version::parse_partial("1.2.3-alpha.1+build.5", Dialect::Standard).unwrap();
+
+        assert_eq!(partial.version.core(), (1, 2, 3));
+        assert_eq!(partial.specified, PartSpecificity::Patch);
+    }
+
+    #[test]
+    fn should_record_specificity_following_dialect() {
+        assert_eq!(
+            Version::parse_partial("v1.2", Dialect::Npm)
+                .unwrap()
+                .specified,
+            PartSpecificity::Minor
+        );
+        assert_eq!(
+            Version::parse_partial("1!2.0.post1", Dialect::Pep440)
+                .unwrap()
+                .specified,
+            PartSpecificity::Minor
+        );
+        assert_eq!(
+            Version::parse_partial("1:2-1", Dialect::Debian)
+                .unwrap()
+                .specified,
+            PartSpecificity::Major
+        );
+    }
+
+    #[test]
+    fn should_order_from_least_specific() {
+        assert!(PartSpecificity::Major < PartSpecificity::Minor);
+        assert!(PartSpecificity::Minor < PartSpecificity::Patch);
+    }
+
+    #[test]
+    fn should_fail_invalid_partial_version() {
+        assert!(Version::parse_partial("1.a", Dialect::Standard).is_err());
+    }
+}
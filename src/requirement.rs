@@ -0,0 +1,1168 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::{Display, Formatter};
+
+use crate::dialect::Dialect;
+use crate::error::Error;
+use crate::{PartSpecificity, PartType, Version};
+
+/// A set of constraints which a version must satisfy, like `>=1.2.0, <2.0.0`.
+///
+/// A requirement is made up of one or more comparators, separated by commas. A version matches the
+/// requirement only when it satisfies **every** comparator.
+///
+/// Range operators are expanded into the equivalent comparators while parsing:
+///
+/// Operator | Example | Equivalent
+/// -|-|-
+/// Caret | `^1.2.3` | `>=1.2.3, <2.0.0`
+/// Caret | `^0.2.3` | `>=0.2.3, <0.3.0`
+/// Caret | `^0.0.3` | `>=0.0.3, <0.0.4`
+/// Caret | `^0.0` | `>=0.0.0, <0.1.0`
+/// Caret | `^0` | `>=0.0.0, <1.0.0`
+/// Tilde | `~1.2.3` | `>=1.2.3, <1.3.0`
+/// Tilde | `~1.2` | `>=1.2.0, <1.3.0`
+/// Tilde | `~1` | `>=1.0.0, <2.0.0`
+/// Wildcard | `1.2.*` | `>=1.2.0, <1.3.0`
+/// Wildcard | `1.*` | `>=1.0.0, <2.0.0`
+///
+/// Wildcards can be written as `*`, `x` or `X`, and may only replace trailing numeric parts.
+///
+/// The Hex dialect also supports the pessimistic operator, where only the last specified part may
+/// increase:
+///
+/// Operator | Example | Equivalent
+/// -|-|-
+/// Pessimistic | `~> 2.1.2` | `>=2.1.2, <2.2.0`
+/// Pessimistic | `~> 2.1` | `>=2.1.0, <3.0.0`
+///
+/// A version without an operator (like `1.2.3`) is an exact match, the same as `=1.2.3`, unless the
+/// dialect treats it as a caret requirement (see [`Dialect::is_bare_requirement_caret`]), as Cargo does.
+///
+/// A requirement which is empty, or only a wildcard (like `*`), has no comparators and matches **any**
+/// version (see [`Requirement::any`]).
+///
+/// ```
+/// use smvr::{Dialect, Requirement, Version};
+///
+/// let requirement = Requirement::parse(">=1.2.0, <2.0.0", Dialect::Standard)?;
+///
+/// assert!(requirement.matches(&Version::parse("1.5.0", Dialect::Standard)?));
+/// assert!(!requirement.matches(&Version::parse("2.0.0", Dialect::Standard)?));
+/// # Ok::<(), smvr::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Requirement {
+    pub comparators: Vec<Comparator>,
+    dialect: Dialect,
+}
+
+/// A set of alternative requirements, like `^1.0.0 || ^2.0.0`.
+///
+/// A version matches the set when it matches **any** of the requirements.
+///
+/// ```
+/// use smvr::{Dialect, Requirement, Version};
+///
+/// let set = Requirement::parse_set("^1.0.0 || ^2.0.0", Dialect::Standard)?;
+///
+/// assert!(set.matches(&Version::parse("2.3.0", Dialect::Standard)?));
+/// assert!(!set.matches(&Version::parse("3.0.0", Dialect::Standard)?));
+/// # Ok::<(), smvr::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequirementSet {
+    pub requirements: Vec<Requirement>,
+}
+
+/// A single constraint inside a requirement, like `>=1.2.0`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparator {
+    pub operator: Operator,
+    pub version: Version,
+}
+
+/// The operation used by a comparator to constrain a version.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operator {
+    /// The version must be equal to the operand (`=1.2.3`, or just `1.2.3` in most dialects).
+    Exact,
+    /// The version must be greater than the operand (`>1.2.3`).
+    Greater,
+    /// The version must be greater than, or equal to, the operand (`>=1.2.3`).
+    GreaterOrEqual,
+    /// The version must be less than the operand (`<1.2.3`).
+    Less,
+    /// The version must be less than, or equal to, the operand (`<=1.2.3`).
+    LessOrEqual,
+}
+
+impl Requirement {
+    /// Parse a comma separated list of comparators into a requirement, following a specific Semver dialect.
+    ///
+    /// Each operand is parsed as a version using the dialect provided.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Requirement};
+    /// let requirement = Requirement::parse(">=1.2.0, <2.0.0", Dialect::Standard);
+    /// # assert_eq!(requirement.unwrap().to_string(), ">=1.2.0, <2.0.0")
+    /// ```
+    pub fn parse(requirement: &str, dialect: Dialect) -> Result<Requirement, Error> {
+        if requirement.trim().is_empty() {
+            return Ok(Requirement::any(dialect));
+        }
+
+        let mut comparators = vec![];
+
+        for comparator in requirement.split(',') {
+            comparators.extend(Comparator::parse(comparator.trim(), dialect)?);
+        }
+
+        Ok(Requirement {
+            comparators,
+            dialect,
+        })
+    }
+
+    /// Parse a list of requirements separated by `||` (like npm) into a set of alternative requirements,
+    /// following a specific Semver dialect.
+    ///
+    /// Each requirement is a comma separated list of comparators, parsed the same as [`Requirement::parse`].
+    ///
+    /// ```
+    /// use smvr::{Dialect, Requirement};
+    /// let set = Requirement::parse_set("^1.0.0 || >=2.1.0, <2.4.0", Dialect::Standard);
+    /// # assert_eq!(set.unwrap().requirements.len(), 2)
+    /// ```
+    pub fn parse_set(requirements: &str, dialect: Dialect) -> Result<RequirementSet, Error> {
+        Ok(RequirementSet {
+            requirements: requirements
+                .split("||")
+                .map(|requirement| Requirement::parse(requirement.trim(), dialect))
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+
+    /// A requirement which matches any version, including prereleases and versions from other dialects.
+    ///
+    /// This is the requirement parsed from an empty string, or a lone wildcard (`*`, `x` or `X`).
+    ///
+    /// ```
+    /// use smvr::{Dialect, Requirement, Version};
+    /// let requirement = Requirement::parse("*", Dialect::Standard)?;
+    ///
+    /// assert_eq!(requirement, Requirement::any(Dialect::Standard));
+    /// assert!(requirement.matches(&Version::parse("1.0.0-alpha", Dialect::Standard)?));
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn any(dialect: Dialect) -> Requirement {
+        Requirement {
+            comparators: vec![],
+            dialect,
+        }
+    }
+
+    /// Whether the requirement matches any version, because it has no comparators.
+    pub fn is_any(&self) -> bool {
+        self.comparators.is_empty()
+    }
+
+    /// Check whether a version satisfies every comparator in the requirement.
+    ///
+    /// Only versions from the same dialect as the requirement can match, unless the requirement
+    /// matches any version (see [`Requirement::any`]).
+    ///
+    /// A prerelease version will only match when at least one comparator has an operand with a
+    /// prerelease identifier, and the same major, minor and patch version. This prevents ranges like
+    /// `>=1.2.0, <2.0.0` unexpectedly matching unstable versions like `1.5.0-alpha`.
+    pub fn matches(&self, version: &Version) -> bool {
+        if self.is_any() {
+            return true;
+        }
+
+        if version.dialect != self.dialect {
+            return false;
+        }
+
+        self.comparators
+            .iter()
+            .all(|comparator| comparator.matches(version))
+            && (!version.is_prerelease()
+                || self.comparators.iter().any(|comparator| {
+                    comparator.version.is_prerelease() && comparator.version.matches_core(version)
+                }))
+    }
+
+    /// The versions in a list which satisfy the requirement, in their original order.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Requirement, Version};
+    /// let versions = [
+    ///     Version::parse("1.2.0", Dialect::Standard)?,
+    ///     Version::parse("2.0.0", Dialect::Standard)?,
+    ///     Version::parse("1.4.0", Dialect::Standard)?,
+    /// ];
+    ///
+    /// let requirement = Requirement::parse("^1.2.0", Dialect::Standard)?;
+    /// assert_eq!(requirement.matches_all(&versions), vec![&versions[0], &versions[2]]);
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn matches_all<'a>(&self, versions: &'a [Version]) -> Vec<&'a Version> {
+        versions
+            .iter()
+            .filter(|version| self.matches(version))
+            .collect()
+    }
+
+    /// The greatest version in a list which satisfies the requirement, following the requirement's
+    /// dialect.
+    ///
+    /// This resolves a requirement to the version which would be chosen as a dependency.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Requirement, Version};
+    /// let versions = [
+    ///     Version::parse("1.2.0", Dialect::Standard)?,
+    ///     Version::parse("2.0.0", Dialect::Standard)?,
+    ///     Version::parse("1.4.0", Dialect::Standard)?,
+    /// ];
+    ///
+    /// let requirement = Requirement::parse("^1.2.0", Dialect::Standard)?;
+    /// assert_eq!(requirement.max_matching(&versions).unwrap().to_string(), "1.4.0");
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn max_matching<'a>(&self, versions: &'a [Version]) -> Option<&'a Version> {
+        crate::max_matching(versions, |version| self.matches(version))
+    }
+
+    /// Check whether at least one version could satisfy both requirements at once.
+    ///
+    /// This is computed from the lower and upper bounds of each requirement's comparators, so the rule
+    /// restricting which prerelease versions match is not considered. Requirements from different
+    /// dialects never intersect, unless either matches any version.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Requirement};
+    /// let requirement = Requirement::parse(">=1.0.0, <2.0.0", Dialect::Standard)?;
+    ///
+    /// assert!(requirement.intersects(&Requirement::parse("^1.5.0", Dialect::Standard)?));
+    /// assert!(!requirement.intersects(&Requirement::parse("^3.0.0", Dialect::Standard)?));
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn intersects(&self, other: &Requirement) -> bool {
+        if self.is_any() || other.is_any() {
+            return !self.bounds().is_empty() && !other.bounds().is_empty();
+        }
+
+        self.dialect == other.dialect && !self.bounds().intersect(other.bounds()).is_empty()
+    }
+
+    /// Check whether every version which satisfies this requirement also satisfies the other.
+    ///
+    /// Like [`Requirement::intersects`], this is computed from the bounds of each requirement's
+    /// comparators. A requirement which no version can satisfy (like `>2.0.0, <1.0.0`) is a subset of
+    /// every requirement.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Requirement};
+    /// let requirement = Requirement::parse("~1.2.3", Dialect::Standard)?;
+    ///
+    /// assert!(requirement.is_subset_of(&Requirement::parse("^1.0.0", Dialect::Standard)?));
+    /// assert!(!requirement.is_subset_of(&Requirement::parse(">=1.2.5", Dialect::Standard)?));
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn is_subset_of(&self, other: &Requirement) -> bool {
+        let bounds = self.bounds();
+
+        if bounds.is_empty() || other.is_any() {
+            return true;
+        }
+
+        !self.is_any() && self.dialect == other.dialect && other.bounds().contains(&bounds)
+    }
+
+    /// The narrowest bounds which every comparator in the requirement allows.
+    fn bounds(&self) -> Bounds<'_> {
+        self.comparators
+            .iter()
+            .fold(Bounds::default(), |bounds, comparator| {
+                let bound = Bound {
+                    version: &comparator.version,
+                    inclusive: matches!(
+                        comparator.operator,
+                        Operator::Exact | Operator::GreaterOrEqual | Operator::LessOrEqual
+                    ),
+                };
+
+                match comparator.operator {
+                    Operator::Exact => bounds.with_lower(bound).with_upper(bound),
+                    Operator::Greater | Operator::GreaterOrEqual => bounds.with_lower(bound),
+                    Operator::Less | Operator::LessOrEqual => bounds.with_upper(bound),
+                }
+            })
+    }
+
+    /// A requirement for versions which are caret-compatible with the base version (like `^1.2.3`).
+    pub(crate) fn caret(base: &Version) -> Result<Requirement, Error> {
+        Ok(Requirement {
+            comparators: Comparator::caret(base.clone(), PartSpecificity::Patch)?,
+            dialect: base.dialect,
+        })
+    }
+
+    /// A requirement for versions which are tilde-compatible with the base version (like `~1.2.3`).
+    pub(crate) fn tilde(base: &Version) -> Result<Requirement, Error> {
+        Ok(Requirement {
+            comparators: Comparator::tilde(base.clone(), PartSpecificity::Patch)?,
+            dialect: base.dialect,
+        })
+    }
+}
+
+/// The range of versions allowed by a requirement, where a missing bound is unbounded.
+#[derive(Default)]
+struct Bounds<'a> {
+    lower: Option<Bound<'a>>,
+    upper: Option<Bound<'a>>,
+}
+
+/// One end of the range of versions allowed by a requirement.
+#[derive(Clone, Copy)]
+struct Bound<'a> {
+    version: &'a Version,
+    inclusive: bool,
+}
+
+impl<'a> Bounds<'a> {
+    /// Narrow the lower bound, keeping whichever bound is more restrictive.
+    fn with_lower(mut self, bound: Bound<'a>) -> Self {
+        if self
+            .lower
+            .map_or(true, |lower| Bound::is_above(&bound, &lower, true))
+        {
+            self.lower = Some(bound);
+        }
+
+        self
+    }
+
+    /// Narrow the upper bound, keeping whichever bound is more restrictive.
+    fn with_upper(mut self, bound: Bound<'a>) -> Self {
+        if self
+            .upper
+            .map_or(true, |upper| Bound::is_above(&upper, &bound, false))
+        {
+            self.upper = Some(bound);
+        }
+
+        self
+    }
+
+    /// The range of versions allowed by both bounds.
+    fn intersect(self, other: Bounds<'a>) -> Self {
+        let bounds = match other.lower {
+            Some(lower) => self.with_lower(lower),
+            None => self,
+        };
+
+        match other.upper {
+            Some(upper) => bounds.with_upper(upper),
+            None => bounds,
+        }
+    }
+
+    /// Whether no version is allowed by the bounds.
+    fn is_empty(&self) -> bool {
+        match (self.lower, self.upper) {
+            (Some(lower), Some(upper)) => match lower.version.cmp(upper.version) {
+                Ordering::Greater => true,
+                Ordering::Equal => !(lower.inclusive && upper.inclusive),
+                Ordering::Less => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Whether every version allowed by the other bounds is also allowed by these bounds.
+    fn contains(&self, other: &Bounds) -> bool {
+        let lower = match (self.lower, other.lower) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(lower), Some(other)) => !Bound::is_above(&lower, &other, true),
+        };
+
+        let upper = match (self.upper, other.upper) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(upper), Some(other)) => !Bound::is_above(&other, &upper, false),
+        };
+
+        lower && upper
+    }
+}
+
+impl Bound<'_> {
+    /// Whether the first bound is strictly more restrictive in the upward direction than the second.
+    ///
+    /// When both bounds have the same version, an exclusive bound is above an inclusive lower bound,
+    /// and an inclusive bound is above an exclusive upper bound.
+    fn is_above(a: &Bound, b: &Bound, lower: bool) -> bool {
+        match a.version.cmp(b.version) {
+            Ordering::Greater => true,
+            Ordering::Less => false,
+            Ordering::Equal if lower => !a.inclusive && b.inclusive,
+            Ordering::Equal => a.inclusive && !b.inclusive,
+        }
+    }
+}
+
+impl RequirementSet {
+    /// Check whether a version satisfies any of the requirements in the set.
+    pub fn matches(&self, version: &Version) -> bool {
+        self.requirements
+            .iter()
+            .any(|requirement| requirement.matches(version))
+    }
+}
+
+impl Comparator {
+    /// Parse a single comparator, made up of an optional operator and a version.
+    ///
+    /// Range operators (like `^`) expand into more than one comparator.
+    fn parse(comparator: &str, dialect: Dialect) -> Result<Vec<Comparator>, Error> {
+        if dialect == Dialect::Hex {
+            if let Some(version) = comparator.strip_prefix("~>") {
+                let partial = Version::parse_partial(version.trim_start(), dialect)?;
+
+                return Comparator::pessimistic(partial.version, partial.specified);
+            }
+        }
+
+        if let Some(version) = comparator.strip_prefix('^') {
+            let partial = Version::parse_partial(version.trim_start(), dialect)?;
+
+            return Comparator::caret(partial.version, partial.specified);
+        }
+
+        if let Some(version) = comparator.strip_prefix('~') {
+            let partial = Version::parse_partial(version.trim_start(), dialect)?;
+
+            return Comparator::tilde(partial.version, partial.specified);
+        }
+
+        if let Some(comparators) = Comparator::wildcard(comparator, dialect)? {
+            return Ok(comparators);
+        }
+
+        let (operator, version) = if let Some(version) = comparator.strip_prefix(">=") {
+            (Operator::GreaterOrEqual, version)
+        } else if let Some(version) = comparator.strip_prefix("<=") {
+            (Operator::LessOrEqual, version)
+        } else if let Some(version) = comparator.strip_prefix('>') {
+            (Operator::Greater, version)
+        } else if let Some(version) = comparator.strip_prefix('<') {
+            (Operator::Less, version)
+        } else if let Some(version) = comparator.strip_prefix('=') {
+            (Operator::Exact, version)
+        } else if dialect.is_bare_requirement_caret() {
+            let partial = Version::parse_partial(comparator, dialect)?;
+
+            return Comparator::caret(partial.version, partial.specified);
+        } else {
+            (Operator::Exact, comparator)
+        };
+
+        Ok(vec![Comparator {
+            operator,
+            version: Version::parse(version.trim_start(), dialect)?,
+        }])
+    }
+
+    /// Expand a caret operator into the range of versions compatible with the base version.
+    ///
+    /// The left-most non-zero part of the version may not change, so `^1.2.3` allows any `1.x.y`,
+    /// `^0.2.3` allows any `0.2.x`, and `^0.0.3` only allows `0.0.3`.
+    ///
+    /// Parts which weren't specified may always change, so `^0` allows any `0.x.y`, and `^0.0` allows
+    /// any `0.0.x`.
+    fn caret(version: Version, specified: PartSpecificity) -> Result<Vec<Comparator>, Error> {
+        let upper_bound = if version.major > 0 || specified == PartSpecificity::Major {
+            version.bump_major_checked()?
+        } else if version.minor > 0 || specified == PartSpecificity::Minor {
+            version.bump_minor_checked()?
+        } else {
+            version.bump_patch_checked()?
+        };
+
+        Ok(Comparator::range(version, upper_bound))
+    }
+
+    /// Expand a tilde operator into the range of versions with only patch-level changes.
+    ///
+    /// When only the major version is specified (like `~1`), minor-level changes are also allowed.
+    fn tilde(version: Version, specified: PartSpecificity) -> Result<Vec<Comparator>, Error> {
+        let upper_bound = if specified == PartSpecificity::Major {
+            version.bump_major_checked()?
+        } else {
+            version.bump_minor_checked()?
+        };
+
+        Ok(Comparator::range(version, upper_bound))
+    }
+
+    /// Expand a pessimistic operator (used by Hex) into the range of versions where only the last
+    /// specified part may increase.
+    ///
+    /// `~> 2.1.2` allows any `2.1.x` from `2.1.2`, and `~> 2.1` allows any `2.x` from `2.1.0`. At least
+    /// the major and minor versions must be specified.
+    fn pessimistic(version: Version, specified: PartSpecificity) -> Result<Vec<Comparator>, Error> {
+        let upper_bound = match specified {
+            PartSpecificity::Major => return Err(Error::MissingPart(PartType::Minor)),
+            PartSpecificity::Minor => version.bump_major_checked()?,
+            PartSpecificity::Patch => version.bump_minor_checked()?,
+        };
+
+        Ok(Comparator::range(version, upper_bound))
+    }
+
+    /// Expand a version containing wildcards (like `1.2.*`) into the range of versions it allows.
+    ///
+    /// Returns `None` when the version does not contain any wildcards.
+    fn wildcard(comparator: &str, dialect: Dialect) -> Result<Option<Vec<Comparator>>, Error> {
+        let is_wildcard = |part: &str| part == "*" || part == "x" || part == "X";
+
+        let mut parts = comparator.split(['-', '+']);
+        let numeric_parts = parts
+            .next()
+            .unwrap_or_default()
+            .split('.')
+            .collect::<Vec<_>>();
+
+        let position = match numeric_parts.iter().position(|part| is_wildcard(part)) {
+            Some(position) => position,
+            None => return Ok(None),
+        };
+
+        let part_types = [PartType::Major, PartType::Minor, PartType::Patch];
+
+        if let Some(part) = numeric_parts[position..]
+            .iter()
+            .position(|part| !is_wildcard(part))
+        {
+            // Wildcards may only replace trailing parts (like 1.*), so a concrete part after
+            // a wildcard (like 1.*.3) is not allowed
+            return Err(Error::InvalidWildcard(
+                part_types
+                    .get(position + part - 1)
+                    .copied()
+                    .unwrap_or(PartType::Patch),
+            ));
+        }
+
+        if numeric_parts.len() > part_types.len() || parts.next().is_some() {
+            // Wildcards cannot be combined with an extra part, prerelease or build metadata
+            return Err(Error::InvalidWildcard(
+                part_types.get(position).copied().unwrap_or(PartType::Patch),
+            ));
+        }
+
+        if position == 0 {
+            // A lone wildcard doesn't constrain the version at all
+            return Ok(Some(vec![]));
+        }
+
+        let version = Version::parse(&numeric_parts[..position].join("."), dialect)?;
+        let upper_bound = if position == 1 {
+            version.bump_major_checked()?
+        } else {
+            version.bump_minor_checked()?
+        };
+
+        Ok(Some(Comparator::range(version, upper_bound)))
+    }
+
+    /// The comparators for a range, from an inclusive lower bound to an exclusive upper bound.
+    fn range(lower_bound: Version, upper_bound: Version) -> Vec<Comparator> {
+        vec![
+            Comparator {
+                operator: Operator::GreaterOrEqual,
+                version: lower_bound,
+            },
+            Comparator {
+                operator: Operator::Less,
+                version: upper_bound,
+            },
+        ]
+    }
+
+    /// Check whether a version satisfies the comparator.
+    ///
+    /// Versions are compared by their precedence, so build metadata is ignored even in dialects which
+    /// use it as a tie-break (like Cargo, where `=1.2.3` matches `1.2.3+build`).
+    ///
+    /// Versions without numeric parts (like the Docker tag `latest`) can't be placed inside a range, so
+    /// only ever match an exact comparator.
+    fn matches(&self, version: &Version) -> bool {
+        if !version.dialect.is_versioned(version)
+            || !self.version.dialect.is_versioned(&self.version)
+        {
+            return self.operator == Operator::Exact && version == &self.version;
+        }
+
+        let ordering = version.cmp_precedence(&self.version);
+
+        match self.operator {
+            Operator::Exact => ordering == Ordering::Equal,
+            Operator::Greater => ordering == Ordering::Greater,
+            Operator::GreaterOrEqual => ordering != Ordering::Less,
+            Operator::Less => ordering == Ordering::Less,
+            Operator::LessOrEqual => ordering != Ordering::Greater,
+        }
+    }
+}
+
+impl Display for Requirement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if self.is_any() {
+            return write!(f, "*");
+        }
+
+        for (i, comparator) in self.comparators.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{}", comparator)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for RequirementSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        for (i, requirement) in self.requirements.iter().enumerate() {
+            if i > 0 {
+                write!(f, " || ")?;
+            }
+
+            write!(f, "{}", requirement)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Comparator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}{}", self.operator, self.version)
+    }
+}
+
+impl Display for Operator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Operator::Exact => "=",
+                Operator::Greater => ">",
+                Operator::GreaterOrEqual => ">=",
+                Operator::Less => "<",
+                Operator::LessOrEqual => "<=",
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use crate::{Dialect, Error, Operator, PartType, Requirement, Version};
+
+    #[test]
+    fn should_parse_each_operator() {
+        let requirement = Requirement::parse(
+            ">1.0.0, >=1.0.0, <2.0.0, <=2.0.0, =1.5.0, 1.5.0",
+            Dialect::Standard,
+        )
+        .unwrap();
+
+        let operators = requirement
+            .comparators
+            .iter()
+            .map(|comparator| comparator.operator)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            operators,
+            [
+                Operator::Greater,
+                Operator::GreaterOrEqual,
+                Operator::Less,
+                Operator::LessOrEqual,
+                Operator::Exact,
+                Operator::Exact
+            ]
+        );
+        assert_eq!(
+            requirement.to_string(),
+            ">1.0.0, >=1.0.0, <2.0.0, <=2.0.0, =1.5.0, =1.5.0"
+        );
+    }
+
+    #[test]
+    fn should_match_version_inside_range() {
+        let requirement = Requirement::parse(">=1.2.0, <2.0.0", Dialect::Standard).unwrap();
+
+        assert!(requirement.matches(&Version::parse("1.5.0", Dialect::Standard).unwrap()));
+        assert!(requirement.matches(&Version::parse("1.2.0", Dialect::Standard).unwrap()));
+        assert!(!requirement.matches(&Version::parse("2.0.0", Dialect::Standard).unwrap()));
+        assert!(!requirement.matches(&Version::parse("1.1.9", Dialect::Standard).unwrap()));
+    }
+
+    #[test]
+    fn should_only_match_prerelease_with_same_core() {
+        let requirement = Requirement::parse(">=1.2.0-alpha, <2.0.0", Dialect::Standard).unwrap();
+
+        assert!(requirement.matches(&Version::parse("1.2.0-beta", Dialect::Standard).unwrap()));
+        assert!(!requirement.matches(&Version::parse("1.5.0-beta", Dialect::Standard).unwrap()));
+        assert!(!requirement.matches(&Version::parse("2.0.0-beta", Dialect::Standard).unwrap()));
+    }
+
+    #[test]
+    fn should_expand_caret_with_major() {
+        let requirement = Requirement::parse("^1.2.3", Dialect::Standard).unwrap();
+
+        assert_eq!(requirement.to_string(), ">=1.2.3, <2.0.0");
+        assert!(requirement.matches(&Version::parse("1.9.9", Dialect::Standard).unwrap()));
+        assert!(!requirement.matches(&Version::parse("2.0.0", Dialect::Standard).unwrap()));
+        assert!(!requirement.matches(&Version::parse("1.2.2", Dialect::Standard).unwrap()));
+    }
+
+    #[test]
+    fn should_expand_caret_with_zero_major() {
+        let requirement = Requirement::parse("^0.2.3", Dialect::Standard).unwrap();
+
+        assert_eq!(requirement.to_string(), ">=0.2.3, <0.3.0");
+        assert!(requirement.matches(&Version::parse("0.2.9", Dialect::Standard).unwrap()));
+        assert!(!requirement.matches(&Version::parse("0.3.0", Dialect::Standard).unwrap()));
+    }
+
+    #[test]
+    fn should_expand_caret_with_zero_major_and_minor() {
+        let requirement = Requirement::parse("^0.0.3", Dialect::Standard).unwrap();
+
+        assert_eq!(requirement.to_string(), ">=0.0.3, <0.0.4");
+        assert!(requirement.matches(&Version::parse("0.0.3", Dialect::Standard).unwrap()));
+        assert!(!requirement.matches(&Version::parse("0.0.4", Dialect::Standard).unwrap()));
+    }
+
+    #[test]
+    fn should_expand_caret_with_partial_version() {
+        for (requirement, expanded) in [
+            ("^0", ">=0.0.0, <1.0.0"),
+            ("^0.0", ">=0.0.0, <0.1.0"),
+            ("^0.1", ">=0.1.0, <0.2.0"),
+            ("^1", ">=1.0.0, <2.0.0"),
+            ("^1.2", ">=1.2.0, <2.0.0"),
+        ] {
+            assert_eq!(
+                Requirement::parse(requirement, Dialect::Standard)
+                    .unwrap()
+                    .to_string(),
+                expanded
+            );
+        }
+
+        let requirement = Requirement::parse("^0", Dialect::Standard).unwrap();
+        assert!(requirement.matches(&Version::parse("0.9.9", Dialect::Standard).unwrap()));
+
+        let requirement = Requirement::parse("^0.0", Dialect::Standard).unwrap();
+        assert!(requirement.matches(&Version::parse("0.0.9", Dialect::Standard).unwrap()));
+        assert!(!requirement.matches(&Version::parse("0.1.0", Dialect::Standard).unwrap()));
+
+        assert_eq!(
+            Requirement::parse("0", Dialect::Cargo).unwrap().to_string(),
+            ">=0.0.0, <1.0.0"
+        );
+    }
+
+    #[test]
+    fn should_expand_tilde_with_patch() {
+        let requirement = Requirement::parse("~1.2.3", Dialect::Standard).unwrap();
+
+        assert_eq!(requirement.to_string(), ">=1.2.3, <1.3.0");
+        assert!(requirement.matches(&Version::parse("1.2.9", Dialect::Standard).unwrap()));
+        assert!(!requirement.matches(&Version::parse("1.3.0", Dialect::Standard).unwrap()));
+    }
+
+    #[test]
+    fn should_expand_tilde_with_minor() {
+        let requirement = Requirement::parse("~1.2", Dialect::Standard).unwrap();
+
+        assert_eq!(requirement.to_string(), ">=1.2.0, <1.3.0");
+        assert!(requirement.matches(&Version::parse("1.2.0", Dialect::Standard).unwrap()));
+        assert!(!requirement.matches(&Version::parse("1.1.9", Dialect::Standard).unwrap()));
+    }
+
+    #[test]
+    fn should_expand_tilde_with_major() {
+        let requirement = Requirement::parse("~1", Dialect::Standard).unwrap();
+
+        assert_eq!(requirement.to_string(), ">=1.0.0, <2.0.0");
+        assert!(requirement.matches(&Version::parse("1.9.0", Dialect::Standard).unwrap()));
+        assert!(!requirement.matches(&Version::parse("2.0.0", Dialect::Standard).unwrap()));
+    }
+
+    #[test]
+    fn should_expand_wildcard_patch() {
+        for wildcard in ["1.2.*", "1.2.x", "1.2.X"] {
+            let requirement = Requirement::parse(wildcard, Dialect::Standard).unwrap();
+
+            assert_eq!(requirement.to_string(), ">=1.2.0, <1.3.0");
+        }
+    }
+
+    #[test]
+    fn should_expand_wildcard_minor() {
+        let requirement = Requirement::parse("1.*", Dialect::Standard).unwrap();
+
+        assert_eq!(requirement.to_string(), ">=1.0.0, <2.0.0");
+        assert_eq!(
+            Requirement::parse("1.*.*", Dialect::Standard).unwrap(),
+            requirement
+        );
+    }
+
+    #[test]
+    fn should_expand_wildcard_major() {
+        let requirement = Requirement::parse("*", Dialect::Standard).unwrap();
+
+        assert_eq!(requirement.to_string(), "*");
+        assert!(requirement.matches(&Version::parse("0.0.1", Dialect::Standard).unwrap()));
+        assert!(requirement.matches(&Version::parse("99.0.0", Dialect::Standard).unwrap()));
+    }
+
+    #[test]
+    fn should_match_any_version() {
+        let versions = [
+            Version::parse("0.0.1", Dialect::Standard).unwrap(),
+            Version::parse("1.0.0-alpha", Dialect::Standard).unwrap(),
+            Version::builder()
+                .major(usize::MAX)
+                .minor(usize::MAX)
+                .patch(usize::MAX)
+                .build()
+                .unwrap(),
+        ];
+
+        for requirement in ["", "*", "x", " X "] {
+            let requirement = Requirement::parse(requirement, Dialect::Standard).unwrap();
+
+            assert!(requirement.is_any());
+            assert_eq!(requirement, Requirement::any(Dialect::Standard));
+
+            for version in &versions {
+                assert!(requirement.matches(version), "{}", version);
+            }
+        }
+    }
+
+    #[test]
+    fn should_ignore_wildcard_alongside_other_comparators() {
+        let requirement = Requirement::parse(">=1.2.0, *", Dialect::Standard).unwrap();
+
+        assert_eq!(requirement.to_string(), ">=1.2.0");
+        assert!(!requirement.is_any());
+        assert!(!requirement.matches(&Version::parse("1.1.0", Dialect::Standard).unwrap()));
+    }
+
+    #[test]
+    fn should_fail_wildcard_before_concrete_part() {
+        let requirement = Requirement::parse("1.*.3", Dialect::Standard);
+
+        assert_eq!(requirement, Err(Error::InvalidWildcard(PartType::Minor)));
+    }
+
+    #[test]
+    fn should_fail_wildcard_with_prerelease() {
+        let requirement = Requirement::parse("1.2.*-alpha", Dialect::Standard);
+
+        assert_eq!(requirement, Err(Error::InvalidWildcard(PartType::Patch)));
+    }
+
+    #[test]
+    fn should_not_treat_prerelease_as_wildcard() {
+        let requirement = Requirement::parse("1.0.0-alpha.x", Dialect::Standard).unwrap();
+
+        assert_eq!(requirement.to_string(), "=1.0.0-alpha.x");
+    }
+
+    #[test]
+    fn should_not_match_version_from_different_dialect() {
+        let requirement = Requirement::parse(">=1.2.0", Dialect::Standard).unwrap();
+
+        assert!(!requirement.matches(&Version::parse("1.5.0", Dialect::Cargo).unwrap()));
+    }
+
+    #[test]
+    fn should_fail_invalid_operand() {
+        let requirement = Requirement::parse(">=1.2.0, <2.a.0", Dialect::Standard);
+
+        assert_eq!(
+            requirement,
+            Err(Error::InvalidCharacter {
+                part: PartType::Minor,
+                byte: b'a',
+                position: 2
+            })
+        );
+    }
+
+    #[test]
+    fn should_resolve_highest_matching_version() {
+        let versions = [
+            "1.1.9",
+            "1.2.0",
+            "2.0.0",
+            "1.9.3",
+            "1.10.0-rc.1",
+            "1.4.2",
+            "0.9.0",
+        ]
+        .map(|version| Version::parse(version, Dialect::Standard).unwrap());
+        let requirement = Requirement::parse("^1.2.0", Dialect::Standard).unwrap();
+
+        assert_eq!(
+            requirement
+                .matches_all(&versions)
+                .iter()
+                .map(|version| version.to_string())
+                .collect::<Vec<_>>(),
+            vec!["1.2.0", "1.9.3", "1.4.2"]
+        );
+        assert_eq!(
+            requirement.max_matching(&versions).unwrap().to_string(),
+            "1.9.3"
+        );
+    }
+
+    #[test]
+    fn should_resolve_nothing_when_no_version_matches() {
+        let versions = [
+            Version::parse("2.0.0", Dialect::Standard).unwrap(),
+            Version::parse("1.5.0", Dialect::Cargo).unwrap(),
+        ];
+        let requirement = Requirement::parse("^1.2.0", Dialect::Standard).unwrap();
+
+        assert!(requirement.matches_all(&versions).is_empty());
+        assert_eq!(requirement.max_matching(&versions), None);
+    }
+
+    #[test]
+    fn should_detect_intersecting_requirements() {
+        let requirement = Requirement::parse(">=1.0.0, <2.0.0", Dialect::Standard).unwrap();
+
+        for (other, intersects) in [
+            ("^1.5.0", true),
+            ("^3.0.0", false),
+            ("^0.9.0", false),
+            ("<1.0.0", false),
+            ("<=1.0.0", true),
+            (">=2.0.0", false),
+            (">1.9.9", true),
+            ("=1.2.3", true),
+            ("=2.0.0", false),
+            ("1.*", true),
+            ("*", true),
+        ] {
+            let other = Requirement::parse(other, Dialect::Standard).unwrap();
+
+            assert_eq!(requirement.intersects(&other), intersects, "{}", other);
+            assert_eq!(other.intersects(&requirement), intersects, "{}", other);
+        }
+    }
+
+    #[test]
+    fn should_not_intersect_unsatisfiable_requirements() {
+        let unsatisfiable = Requirement::parse(">2.0.0, <1.0.0", Dialect::Standard).unwrap();
+        let exclusive = Requirement::parse(">1.0.0, <1.0.0", Dialect::Standard).unwrap();
+
+        assert!(!unsatisfiable.intersects(&Requirement::any(Dialect::Standard)));
+        assert!(!exclusive.intersects(&exclusive));
+        assert!(unsatisfiable.is_subset_of(&exclusive));
+    }
+
+    #[test]
+    fn should_not_intersect_requirements_from_other_dialects() {
+        let standard = Requirement::parse("^1.0.0", Dialect::Standard).unwrap();
+        let cargo = Requirement::parse("^1.0.0", Dialect::Cargo).unwrap();
+
+        assert!(!standard.intersects(&cargo));
+        assert!(!standard.is_subset_of(&cargo));
+        assert!(standard.intersects(&Requirement::any(Dialect::Cargo)));
+    }
+
+    #[test]
+    fn should_detect_subset_requirements() {
+        for (requirement, other, is_subset) in [
+            ("^1.5.0", ">=1.0.0, <2.0.0", true),
+            (">=1.0.0, <2.0.0", "^1.5.0", false),
+            ("~1.2.3", "^1.0.0", true),
+            ("=1.2.3", "^1.2.3", true),
+            ("^1.2.3", "=1.2.3", false),
+            (">=1.0.0, <2.0.0", ">=1.0.0, <=2.0.0", true),
+            (">=1.0.0, <=2.0.0", ">=1.0.0, <2.0.0", false),
+            (">1.0.0", ">=1.0.0", true),
+            (">=1.0.0", ">1.0.0", false),
+            (">=1.0.0", "*", true),
+            ("*", ">=1.0.0", false),
+            ("*", "*", true),
+        ] {
+            let a = Requirement::parse(requirement, Dialect::Standard).unwrap();
+            let b = Requirement::parse(other, Dialect::Standard).unwrap();
+
+            assert_eq!(
+                a.is_subset_of(&b),
+                is_subset,
+                "{} is subset of {}",
+                requirement,
+                other
+            );
+        }
+    }
+
+    #[test]
+    fn should_expand_pessimistic_operator_for_hex() {
+        for (requirement, expanded) in [
+            ("~> 2.1.2", ">=2.1.2, <2.2.0"),
+            ("~>2.1.2", ">=2.1.2, <2.2.0"),
+            ("~> 2.1", ">=2.1.0, <3.0.0"),
+            ("~> 0.1", ">=0.1.0, <1.0.0"),
+            ("~> 2.1.2-rc.1", ">=2.1.2-rc.1, <2.2.0"),
+        ] {
+            assert_eq!(
+                Requirement::parse(requirement, Dialect::Hex)
+                    .unwrap()
+                    .to_string(),
+                expanded,
+                "{}",
+                requirement
+            );
+        }
+    }
+
+    #[test]
+    fn should_match_pessimistic_requirement_for_hex() {
+        let patch = Requirement::parse("~> 2.1.2", Dialect::Hex).unwrap();
+        let minor = Requirement::parse("~> 2.1", Dialect::Hex).unwrap();
+
+        for (version, matches_patch, matches_minor) in [
+            ("2.0.9", false, false),
+            ("2.1.1", false, true),
+            ("2.1.2", true, true),
+            ("2.1.9", true, true),
+            ("2.2.0", false, true),
+            ("2.9.0", false, true),
+            ("3.0.0", false, false),
+        ] {
+            let version = Version::parse(version, Dialect::Hex).unwrap();
+
+            assert_eq!(patch.matches(&version), matches_patch, "{}", version);
+            assert_eq!(minor.matches(&version), matches_minor, "{}", version);
+        }
+    }
+
+    #[test]
+    fn should_fail_pessimistic_operator_without_minor() {
+        assert_eq!(
+            Requirement::parse("~> 2", Dialect::Hex),
+            Err(Error::MissingPart(PartType::Minor))
+        );
+    }
+
+    #[test]
+    fn should_not_support_pessimistic_operator_outside_hex() {
+        assert!(Requirement::parse("~> 2.1.2", Dialect::Standard).is_err());
+    }
+
+    #[test]
+    fn should_match_any_requirement_in_set() {
+        let set = Requirement::parse_set("^1.0.0 || ^2.0.0", Dialect::Standard).unwrap();
+
+        assert!(set.matches(&Version::parse("1.5.0", Dialect::Standard).unwrap()));
+        assert!(set.matches(&Version::parse("2.3.0", Dialect::Standard).unwrap()));
+        assert!(!set.matches(&Version::parse("3.0.0", Dialect::Standard).unwrap()));
+    }
+
+    #[test]
+    fn should_join_comparators_inside_set_member() {
+        let set = Requirement::parse_set(">=1.2.0, <1.3.0 || =2.0.0", Dialect::Standard).unwrap();
+
+        assert_eq!(set.to_string(), ">=1.2.0, <1.3.0 || =2.0.0");
+        assert!(set.matches(&Version::parse("1.2.5", Dialect::Standard).unwrap()));
+        assert!(!set.matches(&Version::parse("1.3.0", Dialect::Standard).unwrap()));
+        assert!(set.matches(&Version::parse("2.0.0", Dialect::Standard).unwrap()));
+    }
+
+    #[test]
+    fn should_fail_invalid_set_member() {
+        assert_eq!(
+            Requirement::parse_set("^1.0.0 || ^2.0.01", Dialect::Standard),
+            Err(Error::InvalidPrecedingZero(PartType::Patch))
+        );
+    }
+
+    #[test]
+    fn should_fail_range_with_overflowing_upper_bound() {
+        let maximum = usize::MAX.to_string();
+
+        assert_eq!(
+            Requirement::parse(&format!("^{}.0.0", maximum), Dialect::Standard),
+            Err(Error::NumberOverflow(PartType::Major))
+        );
+        assert_eq!(
+            Requirement::parse(&format!("~1.{}.0", maximum), Dialect::Standard),
+            Err(Error::NumberOverflow(PartType::Minor))
+        );
+        assert_eq!(
+            Requirement::parse(&format!("{}.*", maximum), Dialect::Standard),
+            Err(Error::NumberOverflow(PartType::Major))
+        );
+    }
+
+    #[test]
+    fn should_treat_bare_version_as_caret_in_cargo() {
+        let version = Version::parse("1.5.0", Dialect::Cargo).unwrap();
+
+        assert!(Requirement::parse("1.2.3", Dialect::Cargo)
+            .unwrap()
+            .matches(&version));
+        assert!(!Requirement::parse("=1.2.3", Dialect::Cargo)
+            .unwrap()
+            .matches(&version));
+        assert_eq!(
+            Requirement::parse("1.2.3", Dialect::Cargo)
+                .unwrap()
+                .to_string(),
+            ">=1.2.3, <2.0.0"
+        );
+    }
+
+    #[test]
+    fn should_treat_bare_version_as_exact_by_default() {
+        for dialect in [Dialect::Standard, Dialect::Npm] {
+            let requirement = Requirement::parse("1.2.3", dialect).unwrap();
+
+            assert_eq!(requirement, Requirement::parse("=1.2.3", dialect).unwrap());
+            assert!(requirement.matches(&Version::parse("1.2.3", dialect).unwrap()));
+            assert!(!requirement.matches(&Version::parse("1.5.0", dialect).unwrap()));
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use core::cmp::Ordering;
+
+use crate::Version;
+
+/// Compare two versions, for use as a sort key (like with `slice::sort_by`).
+///
+/// This never panics, and follows the same total order as [`Version`]'s `Ord` implementation:
+/// versions following the same dialect are ordered using that dialect's rules, and versions following
+/// different dialects are ordered by their dialect.
+///
+/// ```
+/// use smvr::{Dialect, Version};
+/// let mut versions = vec![
+///     Version::parse("1.0.0", Dialect::Standard)?,
+///     Version::parse("1.0.0-rc.1", Dialect::Standard)?,
+///     Version::parse("0.9.0", Dialect::Standard)?,
+/// ];
+///
+/// versions.sort_by(|a, b| smvr::compare(b, a));
+/// assert_eq!(versions[0].to_string(), "1.0.0");
+/// # Ok::<(), smvr::Error>(())
+/// ```
+pub fn compare(a: &Version, b: &Version) -> Ordering {
+    a.cmp(b)
+}
+
+/// Find the greatest version in a list which matches a predicate.
+///
+/// Versions are compared using their ordering, so versions following different dialects never
+/// cause a panic. Instead, mixed dialects are ordered by their dialect first, meaning the result
+/// may not be meaningful. When the list may contain mixed dialects, filter on the dialect in the
+/// predicate.
+///
+/// ```
+/// use smvr::{Dialect, Version};
+/// let versions = [
+///     Version::parse("1.2.0", Dialect::Standard)?,
+///     Version::parse("1.4.0", Dialect::Standard)?,
+///     Version::parse("2.0.0", Dialect::Standard)?,
+/// ];
+///
+/// let version = smvr::max_matching(&versions, |version| version.major == 1);
+/// assert_eq!(version.unwrap().to_string(), "1.4.0");
+/// # Ok::<(), smvr::Error>(())
+/// ```
+pub fn max_matching(
+    versions: &[Version],
+    predicate: impl Fn(&Version) -> bool,
+) -> Option<&Version> {
+    versions.iter().filter(|version| predicate(version)).max()
+}
+
+/// Find the greatest stable version in a list.
+///
+/// Stable versions have no prerelease identifier, and a major version of at least one (see
+/// [`Version::is_stable`]).
+///
+/// ```
+/// use smvr::{Dialect, Version};
+/// let versions = [
+///     Version::parse("1.2.0", Dialect::Standard)?,
+///     Version::parse("2.0.0-rc.1", Dialect::Standard)?,
+/// ];
+///
+/// assert_eq!(smvr::max_stable(&versions).unwrap().to_string(), "1.2.0");
+/// # Ok::<(), smvr::Error>(())
+/// ```
+pub fn max_stable(versions: &[Version]) -> Option<&Version> {
+    max_matching(versions, Version::is_stable)
+}
+
+/// Find the greatest version in a list, including any prerelease versions.
+///
+/// ```
+/// use smvr::{Dialect, Version};
+/// let versions = [
+///     Version::parse("1.2.0", Dialect::Standard)?,
+///     Version::parse("2.0.0-rc.1", Dialect::Standard)?,
+/// ];
+///
+/// assert_eq!(smvr::max_version(&versions).unwrap().to_string(), "2.0.0-rc.1");
+/// # Ok::<(), smvr::Error>(())
+/// ```
+pub fn max_version(versions: &[Version]) -> Option<&Version> {
+    max_matching(versions, |_| true)
+}
+
+/// Aggregations over an iterator of versions, like finding the greatest version.
+///
+/// Versions are compared following the dialect of the first version, and any versions following a
+/// different dialect are skipped.
+///
+/// ```
+/// use smvr::{Dialect, Version, VersionIteratorExt};
+/// let versions = vec![
+///     Version::parse("1.2.0", Dialect::Standard)?,
+///     Version::parse("2.0.0-rc.1", Dialect::Standard)?,
+///     Version::parse("1.4.0", Dialect::Standard)?,
+/// ];
+///
+/// assert_eq!(versions.clone().into_iter().max_version().unwrap().to_string(), "2.0.0-rc.1");
+/// assert_eq!(versions.into_iter().min_version().unwrap().to_string(), "1.2.0");
+/// # Ok::<(), smvr::Error>(())
+/// ```
+pub trait VersionIteratorExt: Iterator<Item = Version> + Sized {
+    /// The greatest version, or the first of any equal versions.
+    fn max_version(self) -> Option<Version> {
+        select(self, Ordering::Greater)
+    }
+
+    /// The least version, or the first of any equal versions.
+    fn min_version(self) -> Option<Version> {
+        select(self, Ordering::Less)
+    }
+}
+
+impl<I: Iterator<Item = Version>> VersionIteratorExt for I {}
+
+/// Select the version which is ordered furthest in one direction, skipping any versions which follow
+/// a different dialect to the first.
+fn select(mut versions: impl Iterator<Item = Version>, direction: Ordering) -> Option<Version> {
+    let first = versions.next()?;
+    let dialect = first.dialect();
+
+    Some(
+        versions
+            .filter(|version| version.dialect() == dialect)
+            .fold(first, |selected, version| {
+                if version.cmp(&selected) == direction {
+                    version
+                } else {
+                    selected
+                }
+            }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+    use core::cmp::Ordering;
+
+    use crate::{
+        compare, max_matching, max_stable, max_version, Dialect, Version, VersionIteratorExt,
+    };
+
+    fn versions(versions: &[&str]) -> Vec<Version> {
+        versions
+            .iter()
+            .map(|version| Version::parse(version, Dialect::Standard).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn should_sort_shuffled_versions_with_compare() {
+        let mut shuffled = versions(&[
+            "1.0.0-beta",
+            "2.0.0",
+            "1.0.0-alpha.1",
+            "0.1.0",
+            "1.0.0",
+            "1.0.0-alpha",
+            "1.10.0",
+            "1.2.0",
+        ]);
+
+        shuffled.sort_by(compare);
+
+        assert_eq!(
+            shuffled
+                .iter()
+                .map(|version| version.to_string())
+                .collect::<Vec<_>>(),
+            [
+                "0.1.0",
+                "1.0.0-alpha",
+                "1.0.0-alpha.1",
+                "1.0.0-beta",
+                "1.0.0",
+                "1.2.0",
+                "1.10.0",
+                "2.0.0",
+            ]
+        );
+    }
+
+    #[test]
+    fn should_compare_mixed_dialects_without_panicking() {
+        let standard = Version::parse("2.0.0", Dialect::Standard).unwrap();
+        let cargo = Version::parse("1.0.0", Dialect::Cargo).unwrap();
+
+        assert_eq!(compare(&standard, &cargo), Ordering::Less);
+        assert_eq!(compare(&cargo, &standard), Ordering::Greater);
+    }
+
+    #[test]
+    fn should_select_highest_stable_version() {
+        let versions = versions(&[
+            "1.0.0",
+            "1.3.0-beta.2",
+            "1.2.1",
+            "2.0.0-alpha",
+            "1.2.0",
+            "0.9.0",
+        ]);
+
+        assert_eq!(max_stable(&versions).unwrap().to_string(), "1.2.1");
+    }
+
+    #[test]
+    fn should_select_highest_version_including_prereleases() {
+        let versions = versions(&["1.0.0", "2.0.0-alpha", "1.2.1"]);
+
+        assert_eq!(max_version(&versions).unwrap().to_string(), "2.0.0-alpha");
+    }
+
+    #[test]
+    fn should_select_highest_version_matching_predicate() {
+        let versions = versions(&["1.0.0", "1.9.3", "2.0.0", "1.10.0-rc.1"]);
+
+        assert_eq!(
+            max_matching(&versions, |version| version.major == 1
+                && !version.is_prerelease())
+            .unwrap()
+            .to_string(),
+            "1.9.3"
+        );
+    }
+
+    #[test]
+    fn should_return_none_when_nothing_matches() {
+        let versions = versions(&["1.0.0-alpha", "0.1.0"]);
+
+        assert_eq!(max_stable(&versions), None);
+        assert_eq!(max_version(&[]), None);
+    }
+
+    #[test]
+    fn should_aggregate_iterator_mixing_prereleases() {
+        let versions = versions(&["1.0.0", "2.0.0-alpha", "1.2.1", "1.0.0-rc.1", "2.0.0-beta"]);
+
+        assert_eq!(
+            versions
+                .clone()
+                .into_iter()
+                .max_version()
+                .unwrap()
+                .to_string(),
+            "2.0.0-beta"
+        );
+        assert_eq!(
+            versions.into_iter().min_version().unwrap().to_string(),
+            "1.0.0-rc.1"
+        );
+    }
+
+    #[test]
+    fn should_skip_versions_from_other_dialects() {
+        let versions = [
+            Version::parse("1.0.0", Dialect::Standard).unwrap(),
+            Version::parse("9.0.0", Dialect::Cargo).unwrap(),
+            Version::parse("0.1.0", Dialect::Npm).unwrap(),
+            Version::parse("1.5.0", Dialect::Standard).unwrap(),
+        ];
+
+        let max = versions.clone().into_iter().max_version().unwrap();
+        let min = versions.into_iter().min_version().unwrap();
+
+        assert_eq!(
+            (max.to_string(), max.dialect()),
+            ("1.5.0".to_string(), Dialect::Standard)
+        );
+        assert_eq!(
+            (min.to_string(), min.dialect()),
+            ("1.0.0".to_string(), Dialect::Standard)
+        );
+    }
+
+    #[test]
+    fn should_keep_first_of_equal_versions() {
+        let versions = versions(&["1.0.0+a", "1.0.0+b"]);
+
+        assert_eq!(
+            versions
+                .clone()
+                .into_iter()
+                .max_version()
+                .unwrap()
+                .to_string(),
+            "1.0.0+a"
+        );
+        assert_eq!(
+            versions.into_iter().min_version().unwrap().to_string(),
+            "1.0.0+a"
+        );
+        assert_eq!(Vec::<Version>::new().into_iter().max_version(), None);
+    }
+}
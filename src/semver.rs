@@ -0,0 +1,155 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::dialect::Dialect;
+use crate::error::Error;
+use crate::{BuildMetadata, PartType, Prerelease, PrereleaseComponent, Version};
+
+/// Convert a version from the `semver` crate, following the standard dialect.
+///
+/// Numeric prerelease identifiers are kept as numbers, and any others as strings. This fails with an
+/// [`Error::NumberOverflow`] error when a number (including a numeric prerelease identifier) doesn't
+/// fit in a usize.
+impl TryFrom<semver::Version> for Version {
+    type Error = Error;
+
+    fn try_from(version: semver::Version) -> Result<Self, Self::Error> {
+        let number =
+            |number: u64, part| usize::try_from(number).map_err(|_| Error::NumberOverflow(part));
+
+        let prerelease = if version.pre.is_empty() {
+            None
+        } else {
+            Some(
+                version
+                    .pre
+                    .as_str()
+                    .split('.')
+                    .map(PrereleaseComponent::new)
+                    .collect::<Result<Vec<_>, _>>()?,
+            )
+        };
+
+        let build_metadata =
+            (!version.build.is_empty()).then(|| version.build.as_str().to_string());
+
+        Ok(Version::new(
+            number(version.major, PartType::Major)?,
+            number(version.minor, PartType::Minor)?,
+            number(version.patch, PartType::Patch)?,
+            prerelease,
+            build_metadata,
+            None,
+            Dialect::Standard,
+        ))
+    }
+}
+
+/// Convert a version into a version from the `semver` crate.
+///
+/// Only the major, minor and patch versions, prerelease identifier and build metadata are converted, so
+/// any parts specific to the version's dialect (like a PEP 440 epoch) are dropped. This fails when the
+/// prerelease identifier or build metadata aren't valid SemVer identifiers.
+impl TryFrom<Version> for semver::Version {
+    type Error = semver::Error;
+
+    fn try_from(version: Version) -> Result<Self, Self::Error> {
+        let pre = match &version.prerelease {
+            Prerelease::Empty => semver::Prerelease::EMPTY,
+            Prerelease::Identifier(components) => semver::Prerelease::new(
+                &components
+                    .iter()
+                    .map(|component| component.to_string())
+                    .collect::<Vec<String>>()
+                    .join("."),
+            )?,
+        };
+
+        let build = match &version.build_metadata {
+            BuildMetadata::Empty => semver::BuildMetadata::EMPTY,
+            BuildMetadata::Identifier(identifier) => semver::BuildMetadata::new(identifier)?,
+        };
+
+        Ok(semver::Version {
+            major: version.major as u64,
+            minor: version.minor as u64,
+            patch: version.patch as u64,
+            pre,
+            build,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::{Dialect, Error, PartType, PrereleaseComponent, Version};
+
+    #[test]
+    fn should_convert_from_semver_version() {
+        let version =
+            Version::try_from(semver::Version::parse("1.2.3-alpha.1+build").unwrap()).unwrap();
+
+        assert_eq!(version.core(), (1, 2, 3));
+        assert_eq!(
+            version.prerelease.components(),
+            &[
+                PrereleaseComponent::String("alpha".to_string()),
+                PrereleaseComponent::Number(1)
+            ]
+        );
+        assert_eq!(version.build_metadata.components(), ["build"]);
+        assert_eq!(version.dialect(), Dialect::Standard);
+    }
+
+    #[test]
+    fn should_round_trip_through_semver_version() {
+        let version = Version::parse("1.2.3-alpha.1+build", Dialect::Standard).unwrap();
+
+        let converted = semver::Version::try_from(version.clone()).unwrap();
+        assert_eq!(converted.to_string(), "1.2.3-alpha.1+build");
+
+        let round_tripped = Version::try_from(converted).unwrap();
+        assert_eq!(round_tripped, version);
+        assert_eq!(round_tripped.to_string(), version.to_string());
+        assert_eq!(round_tripped.build_metadata, version.build_metadata);
+    }
+
+    #[test]
+    fn should_preserve_numeric_and_string_prerelease_components() {
+        let version = Version::try_from(semver::Version::parse("1.0.0-rc.10.1a").unwrap()).unwrap();
+
+        assert_eq!(
+            version.prerelease.components(),
+            &[
+                PrereleaseComponent::String("rc".to_string()),
+                PrereleaseComponent::Number(10),
+                PrereleaseComponent::String("1a".to_string())
+            ]
+        );
+        assert!(
+            version > Version::try_from(semver::Version::parse("1.0.0-rc.9").unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_convert_standard_parts_of_other_dialects() {
+        let version = Version::parse("1!1.0rc1+ubuntu.1", Dialect::Pep440).unwrap();
+
+        assert_eq!(
+            semver::Version::try_from(version).unwrap().to_string(),
+            "1.0.0-rc.1+ubuntu.1"
+        );
+    }
+
+    #[test]
+    fn should_fail_to_convert_numbers_which_overflow() {
+        let version = semver::Version::parse("1.0.0-rc.99999999999999999999999").unwrap();
+
+        assert_eq!(
+            Version::try_from(version),
+            Err(Error::NumberOverflow(PartType::Prerelease))
+        );
+    }
+}
@@ -0,0 +1,170 @@
+//! Serialization of versions using [`serde`](https://serde.rs).
+//!
+//! Versions serialize to the string produced by their dialect, but (as a string doesn't record its
+//! dialect) always deserialize following the standard dialect. Versions from other dialects can be
+//! deserialized using [`dialect`].
+
+use core::fmt::Formatter;
+
+use serde::de::{DeserializeSeed, Error as DeserializeError, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::dialect::Dialect;
+use crate::Version;
+
+/// Serialize a version into its string representation, as produced by its dialect.
+impl Serialize for Version {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserialize a version from a string, following the standard dialect.
+///
+/// Versions serialized from other dialects may fail to deserialize (like the npm `v1.2.3`), or
+/// deserialize as a standard version (like a Cargo version). Use [`dialect`] to deserialize them
+/// following their own dialect.
+impl<'de> Deserialize<'de> for Version {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(VersionVisitor(Dialect::Standard))
+    }
+}
+
+/// Deserialize a version from a string, following a specific dialect.
+///
+/// This can be used from a `#[serde(deserialize_with = "...")]` function to deserialize a field
+/// following the dialect it was serialized from.
+///
+/// ```
+/// use serde::de::DeserializeSeed;
+/// use smvr::{Dialect, Version};
+/// fn deserialize_npm<'de, D>(deserializer: D) -> Result<Version, D::Error>
+/// where
+///     D: serde::Deserializer<'de>,
+/// {
+///     smvr::serde::dialect(Dialect::Npm).deserialize(deserializer)
+/// }
+///
+/// let version = deserialize_npm(&mut serde_json::Deserializer::from_str("\"v1.2.3\""))?;
+/// assert_eq!(version, Version::parse("v1.2.3", Dialect::Npm)?);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn dialect(dialect: Dialect) -> DialectSeed {
+    DialectSeed(dialect)
+}
+
+/// A [`DeserializeSeed`] which deserializes a version following a specific dialect, created using
+/// [`dialect`].
+#[derive(Clone, Copy, Debug)]
+pub struct DialectSeed(Dialect);
+
+impl<'de> DeserializeSeed<'de> for DialectSeed {
+    type Value = Version;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(VersionVisitor(self.0))
+    }
+}
+
+struct VersionVisitor(Dialect);
+
+impl<'de> Visitor<'de> for VersionVisitor {
+    type Value = Version;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("a semantic version string")
+    }
+
+    fn visit_str<E>(self, version: &str) -> Result<Self::Value, E>
+    where
+        E: DeserializeError,
+    {
+        Version::parse(version, self.0)
+            .map_err(|error| E::custom(format_args!("invalid version: {}", error)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use serde::de::DeserializeSeed;
+
+    use crate::{Dialect, Version};
+
+    #[test]
+    fn should_serialize_version_to_string() {
+        let version = Version::parse("1.2.3-alpha.1+build.5", Dialect::Standard).unwrap();
+
+        assert_eq!(
+            serde_json::to_string(&version).unwrap(),
+            "\"1.2.3-alpha.1+build.5\""
+        );
+    }
+
+    #[test]
+    fn should_round_trip_version() {
+        let version = Version::parse("1.2.3-alpha.1+build.5", Dialect::Standard).unwrap();
+
+        let deserialized: Version =
+            serde_json::from_str(&serde_json::to_string(&version).unwrap()).unwrap();
+
+        assert_eq!(deserialized, version);
+        assert_eq!(deserialized.build_metadata, version.build_metadata);
+    }
+
+    #[test]
+    fn should_fail_deserializing_invalid_version() {
+        let version = serde_json::from_str::<Version>("\"1.02.3\"");
+
+        assert!(version.is_err());
+        assert!(version.unwrap_err().to_string().starts_with(
+            "invalid version: the minor version part contains an invalid leading zero"
+        ));
+    }
+
+    #[test]
+    fn should_deserialize_other_dialects_as_standard() {
+        let npm = Version::parse("v1.2.3", Dialect::Npm).unwrap();
+        let cargo = Version::parse("1.2.3+build", Dialect::Cargo).unwrap();
+
+        assert!(serde_json::from_str::<Version>(&serde_json::to_string(&npm).unwrap()).is_err());
+        assert_eq!(
+            serde_json::from_str::<Version>(&serde_json::to_string(&cargo).unwrap())
+                .unwrap()
+                .dialect(),
+            Dialect::Standard
+        );
+    }
+
+    #[test]
+    fn should_round_trip_version_following_dialect() {
+        for (version, dialect) in [
+            ("v1.2.3", Dialect::Npm),
+            ("1.2.3+build", Dialect::Cargo),
+            ("v2.0.0+incompatible", Dialect::Go),
+            ("1!2.0rc1", Dialect::Pep440),
+            ("1:2.0-1", Dialect::Debian),
+        ] {
+            let version = Version::parse(version, dialect).unwrap();
+            let serialized = serde_json::to_string(&version).unwrap();
+
+            let deserialized = crate::serde::dialect(dialect)
+                .deserialize(&mut serde_json::Deserializer::from_str(&serialized))
+                .unwrap();
+
+            assert_eq!(deserialized, version);
+            assert_eq!(deserialized.to_string(), version.to_string());
+            assert_eq!(deserialized.dialect(), dialect);
+        }
+    }
+}
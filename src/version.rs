@@ -1,24 +1,33 @@
-use alloc::borrow::ToOwned;
 use alloc::string::{String, ToString};
 use alloc::vec;
 use alloc::vec::Vec;
 use core::cmp::Ordering;
 use core::fmt::{Debug, Display};
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
 
+use crate::builder::VersionBuilder;
+use crate::bump::{BumpOptions, Increment};
 use crate::component::{BuildMetadata, PartType, Prerelease, PrereleaseComponent};
 use crate::dialect;
-use crate::dialect::Dialect::Standard;
-use crate::dialect::{CapturedBytes, Dialect, DialectParser, NextPartType, RemainingUnparsedBytes};
+use crate::dialect::Dialect::{Go, Standard};
+use crate::dialect::{Dialect, Extension, NextPartType, RemainingUnparsedBytes};
 use crate::error::Error;
+use crate::partial::{PartSpecificity, PartialVersion};
+use crate::requirement::Requirement;
+use crate::version_core::VersionCore;
+use crate::version_parts::VersionParts;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Version {
     pub major: usize,
     pub minor: usize,
     pub patch: usize,
     pub prerelease: Prerelease,
     pub build_metadata: BuildMetadata,
-    dialect: Dialect,
+    pub(crate) prefix: Option<char>,
+    pub(crate) dialect: Dialect,
+    pub(crate) extension: Option<Extension>,
 }
 
 impl Version {
@@ -30,280 +39,3655 @@ impl Version {
     /// # assert_eq!(version.unwrap().to_string(), "0.1.4-beta".to_string())
     /// ```
     pub fn parse(version: &str, dialect: Dialect) -> Result<Version, Error> {
-        let version_bytes = version.as_bytes();
+        dialect.parse(version)
+    }
+
+    /// Parse the leading version from a string, following a specific Semver dialect, and return it
+    /// alongside the remaining unparsed suffix (like `" (stable)"` in `1.2.3 (stable)`).
+    ///
+    /// Parsing stops at the first byte which can't belong to the version, so long as the version before
+    /// it is complete. Any other error (like a leading zero, or a version string which doesn't begin
+    /// with a version at all) is returned as normal.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let (version, remaining) = Version::parse_lossy("1.2.3 (stable)", Dialect::Standard)?;
+    /// assert_eq!(version.to_string(), "1.2.3");
+    /// assert_eq!(remaining, " (stable)");
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn parse_lossy(version: &str, dialect: Dialect) -> Result<(Version, &str), Error> {
+        let mut end = version.len();
+
+        loop {
+            match Version::parse(&version[..end], dialect) {
+                Ok(parsed) => return Ok((parsed, &version[end..])),
+                Err(Error::InvalidCharacter { position, .. })
+                    if position > 0 && position < end && version.is_char_boundary(position) =>
+                {
+                    // Everything before the invalid byte may still be a complete version
+                    end = position;
+                }
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
+    /// Parse a string into a Version instance, following the standard dialect.
+    ///
+    /// This is the same dialect used by the [`FromStr`] and [`TryFrom`] implementations, so
+    /// `Version::parse_default(version)` is equivalent to `version.parse::<Version>()`.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let version = Version::parse_default("0.1.4-beta")?;
+    /// assert_eq!(version, Version::parse("0.1.4-beta", Dialect::Standard)?);
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn parse_default(version: &str) -> Result<Version, Error> {
+        Version::parse(version, Standard)
+    }
+
+    /// Parse a string into a Version instance, following a specific Semver dialect, ignoring any
+    /// surrounding ASCII whitespace (like a version copied from a log line).
+    ///
+    /// Whitespace inside the version (like `1. 2.3`) is still invalid. The position of an
+    /// [`Error::InvalidCharacter`] error is relative to the start of the original string.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let version = Version::parse_relaxed_whitespace(" 1.2.3\t\n", Dialect::Standard)?;
+    /// assert_eq!(version.to_string(), "1.2.3");
+    /// assert!(Version::parse_relaxed_whitespace("1. 2.3", Dialect::Standard).is_err());
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn parse_relaxed_whitespace(version: &str, dialect: Dialect) -> Result<Version, Error> {
+        let trimmed = version.trim_start_matches(|c: char| c.is_ascii_whitespace());
+        let leading_whitespace = version.len() - trimmed.len();
+
+        Version::parse(
+            trimmed.trim_end_matches(|c: char| c.is_ascii_whitespace()),
+            dialect,
+        )
+        .map_err(|error| match error {
+            Error::InvalidCharacter {
+                part,
+                byte,
+                position,
+            } => Error::InvalidCharacter {
+                part,
+                byte,
+                position: position + leading_whitespace,
+            },
+            error => error,
+        })
+    }
+
+    /// Parse raw bytes into a Version instance, following a specific Semver dialect, without first
+    /// converting them into a string.
+    ///
+    /// This is intended for untrusted input, and never panics. Dialects which are parsed one byte at
+    /// a time (like the standard dialect) operate directly on the bytes, and only the prerelease
+    /// identifier and build metadata are converted into strings. Other dialects (like PEP 440)
+    /// convert the bytes into a string first, so report invalid UTF-8 ahead of any other error.
+    ///
+    /// Bytes which aren't valid UTF-8 return an [`Error::InvalidUtf8`] error, reported against the
+    /// part the first invalid byte appears in (judged by the standard `.`, `-` and `+` separators),
+    /// unless an error is found before it. Otherwise, the bytes are parsed the same as
+    /// [`Version::parse`].
+    ///
+    /// ```
+    /// use smvr::{Dialect, Error, PartType, Version};
+    /// let version = Version::parse_bytes(b"1.2.3-beta+build", Dialect::Standard)?;
+    /// assert_eq!(version, Version::parse("1.2.3-beta+build", Dialect::Standard)?);
+    ///
+    /// let version = Version::parse_bytes(b"1.2.3-\xff", Dialect::Standard);
+    /// assert_eq!(version, Err(Error::InvalidUtf8(PartType::Prerelease)));
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn parse_bytes(version: &[u8], dialect: Dialect) -> Result<Version, Error> {
+        dialect.parse_bytes(version).map_err(|error| match error {
+            // Every valid byte is ASCII, so the bytes are only checked for invalid UTF-8 once an
+            // unexpected byte has been found, and it's only reported when it's the first error
+            Error::InvalidCharacter { position, .. }
+                if core::str::from_utf8(version)
+                    .is_err_and(|utf8| utf8.valid_up_to() <= position) =>
+            {
+                Version::from_utf8(version).err().unwrap_or(error)
+            }
+            error => error,
+        })
+    }
+
+    /// Parse raw bytes into a Version instance, following a specific Semver dialect.
+    ///
+    /// This is the same as [`Version::parse_bytes`], so never panics, and returns an
+    /// [`Error::InvalidUtf8`] error for bytes which aren't valid UTF-8.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Error, PartType, Version};
+    /// let version = Version::try_parse_bytes(b"1.\xff.3", Dialect::Standard);
+    /// assert_eq!(version, Err(Error::InvalidUtf8(PartType::Minor)));
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn try_parse_bytes(version: &[u8], dialect: Dialect) -> Result<Version, Error> {
+        Version::parse_bytes(version, dialect)
+    }
+
+    /// Convert raw bytes into a version string, reporting invalid UTF-8 against the part the first
+    /// invalid byte appears in (judged by the standard `.`, `-` and `+` separators).
+    pub(crate) fn from_utf8(version: &[u8]) -> Result<&str, Error> {
+        core::str::from_utf8(version).map_err(|error| {
+            let valid = &version[..error.valid_up_to()];
+
+            Error::InvalidUtf8(if valid.contains(&b'+') {
+                PartType::BuildMetadata
+            } else if valid.contains(&b'-') {
+                PartType::Prerelease
+            } else {
+                match valid.iter().filter(|byte| **byte == b'.').count() {
+                    0 => PartType::Major,
+                    1 => PartType::Minor,
+                    _ => PartType::Patch,
+                }
+            })
+        })
+    }
+
+    /// Parse a string into a Version instance, following a specific Semver dialect, recording which of
+    /// the numeric parts were specified.
+    ///
+    /// Version strings may omit their minor and patch parts (like `1` or `1.2`), which default to zero.
+    ///
+    /// ```
+    /// use smvr::{Dialect, PartSpecificity, Version};
+    /// let partial = Version::parse_partial("1.2", Dialect::Standard)?;
+    /// assert_eq!(partial.version.to_string(), "1.2.0");
+    /// assert_eq!(partial.specified, PartSpecificity::Minor);
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn parse_partial(version: &str, dialect: Dialect) -> Result<PartialVersion, Error> {
+        let parsed = Version::parse(version, dialect)?;
+
+        // Skip any epoch, as it's not one of the numeric parts
+        let release = dialect.strip_epoch(version);
+
+        // The version has already been validated, so the numeric parts are always the leading
+        // dot-separated numbers (after any prefix)
+        let specified_parts = release
+            .trim()
+            .trim_start_matches(['v', 'V'])
+            .split(|c: char| !c.is_ascii_digit() && c != '.')
+            .next()
+            .unwrap_or_default()
+            .split('.')
+            .filter(|part| !part.is_empty())
+            .count();
+
+        Ok(PartialVersion {
+            version: parsed,
+            specified: PartSpecificity::from_parts(specified_parts),
+        })
+    }
+
+    /// Parse a string into a Version instance, following a specific Semver dialect, requiring every
+    /// numeric part to be specified (like `1.2.3`), as strict SemVer does.
+    ///
+    /// Unlike [`Version::parse`], which defaults omitted parts to zero, a version which omits its minor
+    /// or patch part (like `1` or `1.2`) returns an [`Error::MissingPart`] error for the first part
+    /// omitted.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Error, PartType, Version};
+    /// assert!(Version::parse_strict("1.2.3", Dialect::Standard).is_ok());
+    /// assert_eq!(
+    ///     Version::parse_strict("1.2", Dialect::Standard),
+    ///     Err(Error::MissingPart(PartType::Patch))
+    /// );
+    /// ```
+    pub fn parse_strict(version: &str, dialect: Dialect) -> Result<Version, Error> {
+        let partial = Version::parse_partial(version, dialect)?;
+
+        match partial.specified {
+            PartSpecificity::Major => Err(Error::MissingPart(PartType::Minor)),
+            PartSpecificity::Minor => Err(Error::MissingPart(PartType::Patch)),
+            PartSpecificity::Patch => Ok(partial.version),
+        }
+    }
+
+    /// Parse a string into a Version instance, following a specific Semver dialect, collecting every
+    /// error found rather than returning the first.
+    ///
+    /// Errors are returned in the order they occur in the version string. Dialects which are not
+    /// parsed one byte at a time (like PEP 440 and Debian) only return the first error.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Error, PartType, Version};
+    /// let errors = Version::parse_collecting("1.01.x", Dialect::Standard).unwrap_err();
+    /// assert_eq!(
+    ///     errors,
+    ///     vec![
+    ///         Error::InvalidPrecedingZero(PartType::Minor),
+    ///         Error::InvalidCharacter { part: PartType::Patch, byte: b'x', position: 5 }
+    ///     ]
+    /// );
+    /// ```
+    pub fn parse_collecting(version: &str, dialect: Dialect) -> Result<Version, Vec<Error>> {
+        dialect.parse_collecting(version)
+    }
+
+    /// Parse a list of versions with one version per line (like the contents of a `versions.txt`
+    /// file), following a specific Semver dialect.
+    ///
+    /// Whitespace around each line is trimmed and blank lines are skipped. Every other line is parsed
+    /// separately, so a result is returned for each, in order.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let versions = Version::parse_all("1.0.0\n\n  1.1.0\n1.x\n", Dialect::Standard);
+    /// assert_eq!(versions.len(), 3);
+    /// assert_eq!(versions[1], Ok(Version::parse("1.1.0", Dialect::Standard)?));
+    /// assert!(versions[2].is_err());
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn parse_all(input: &str, dialect: Dialect) -> Vec<Result<Version, Error>> {
+        input
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| Version::parse(line, dialect))
+            .collect()
+    }
+
+    /// Validate a version string following a specific Semver dialect, without constructing a Version
+    /// instance.
+    ///
+    /// The same errors are returned as when parsing, but (for dialects which are parsed one byte at a time)
+    /// no allocations are made, which makes this well suited to checking large numbers of version strings.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// assert!(Version::validate("0.1.4-beta", Dialect::Standard).is_ok());
+    /// assert!(Version::validate("0.01.4", Dialect::Standard).is_err());
+    /// ```
+    pub fn validate(version: &str, dialect: Dialect) -> Result<(), Error> {
+        dialect.validate(version)
+    }
+
+    /// Validate a version string one byte at a time, following the same rules as [`Version::parse_parts`],
+    /// but without converting any of the parts into an owned Version instance.
+    pub(crate) fn validate_parts(version: &str, dialect: Dialect) -> Result<(), Error> {
+        Version::scan_parts(version.as_bytes(), dialect, None, |part_type, part| {
+            match part_type {
+                PartType::Major | PartType::Minor | PartType::Patch => {
+                    Version::parse_number(part, part_type)?;
+                }
+                PartType::Prerelease if Version::is_numeric(part) => {
+                    Version::parse_prerelease_number(part)?;
+                }
+                PartType::Prerelease | PartType::BuildMetadata => {}
+            }
+
+            Ok(())
+        })?;
+
+        Ok(())
+    }
+
+    /// Parse a string into a Version instance one byte at a time, allowing the dialect to validate
+    /// each byte and decide when to transition between parts.
+    pub(crate) fn parse_parts(version: &str, dialect: Dialect) -> Result<Version, Error> {
+        Version::parse_parts_with(version.as_bytes(), dialect, None)
+    }
+
+    /// Parse raw bytes into a Version instance one byte at a time, following the same rules as
+    /// [`Version::parse_parts`], without first converting them into a string.
+    pub(crate) fn parse_parts_bytes(version: &[u8], dialect: Dialect) -> Result<Version, Error> {
+        Version::parse_parts_with(version, dialect, None)
+    }
+
+    /// Parse a string into a Version instance one byte at a time, following the same rules as
+    /// [`Version::parse_parts`], but collecting every error instead of returning the first.
+    pub(crate) fn parse_parts_collecting(
+        version: &str,
+        dialect: Dialect,
+    ) -> Result<Version, Vec<Error>> {
+        let mut errors = vec![];
+
+        match Version::parse_parts_with(version.as_bytes(), dialect, Some(&mut errors)) {
+            Ok(version) if errors.is_empty() => Ok(version),
+            Ok(_) => Err(errors),
+            Err(error) => Err(vec![error]),
+        }
+    }
+
+    /// Parse the bytes of a version string into a Version instance one byte at a time.
+    ///
+    /// When a collection of errors is provided, any errors are added to it and parsing continues
+    /// as if the offending byte or part was valid.
+    fn parse_parts_with(
+        version: &[u8],
+        dialect: Dialect,
+        errors: Option<&mut Vec<Error>>,
+    ) -> Result<Version, Error> {
+        let (mut major, mut minor, mut patch) = (0, 0, 0);
+        let (mut prerelease, mut build_metadata) = (vec![], None);
+
+        let prefix = Version::scan_parts(version, dialect, errors, |part_type, part| {
+            match part_type {
+                PartType::Major => major = Version::parse_number(part, part_type)?,
+                PartType::Minor => minor = Version::parse_number(part, part_type)?,
+                PartType::Patch => patch = Version::parse_number(part, part_type)?,
+                PartType::Prerelease => prerelease.push(Version::parse_prerelease_component(part)?),
+                PartType::BuildMetadata if !part.is_empty() => {
+                    build_metadata = Some(Version::parse_string(part, part_type)?)
+                }
+                PartType::BuildMetadata => {}
+            }
+
+            Ok(())
+        })?;
+
+        Ok(Version::new(
+            major,
+            minor,
+            patch,
+            if !prerelease.is_empty() {
+                Some(prerelease)
+            } else {
+                None
+            },
+            build_metadata,
+            prefix,
+            dialect,
+        ))
+    }
+
+    /// Scan a version string one part at a time, following the dialect, and pass each part to
+    /// the provided closure as a slice of the original version string.
+    ///
+    /// Each prerelease identifier (like `alpha` and `1` in `1.0.0-alpha.1`) is passed separately. The
+    /// prefix stripped by the dialect, if any, is returned.
+    ///
+    /// When a collection of errors is provided, errors (including those returned by the closure) are
+    /// added to it in the order they're found, rather than being returned.
+    fn scan_parts<'a>(
+        version: &'a [u8],
+        dialect: Dialect,
+        mut errors: Option<&mut Vec<Error>>,
+        mut on_part: impl FnMut(PartType, &'a [u8]) -> Result<(), Error>,
+    ) -> Result<Option<char>, Error> {
+        let (prefix, version_bytes) = dialect.parse_prefix(version);
+
+        let mut current_part_type = PartType::Major;
+        let mut remaining = version_bytes;
+        loop {
+            let previous_errors = errors.as_deref().map_or(0, |errors| errors.len());
+
+            let (part, r, next_part_type) = Version::parse_part(
+                remaining,
+                version.len() - remaining.len(),
+                dialect,
+                current_part_type,
+                errors.as_deref_mut(),
+            )?;
+
+            let result = if Version::is_empty_part(part, current_part_type) {
+                // Minor and patch may be omitted entirely (like 1.2), but once a numeric part
+                // has been started it must contain at least one digit. Likewise, every prerelease
+                // and build metadata identifier must contain at least one character (so 1.0.0-,
+                // 1.0.0-alpha..1, 1.0.0+ and 1.0.0+a..b are all invalid)
+                Err(Error::EmptyPart(current_part_type))
+            } else if errors.as_deref().map_or(0, |errors| errors.len()) > previous_errors {
+                // A part containing invalid bytes can't be converted, and doing so would only
+                // repeat the errors already collected
+                Ok(())
+            } else {
+                on_part(current_part_type, part)
+            };
+
+            if let Err(error) = result {
+                match errors.as_deref_mut() {
+                    Some(errors) => errors.push(error),
+                    None => return Err(error),
+                }
+            }
+
+            match next_part_type {
+                Some(next_part_type) => current_part_type = next_part_type,
+                None => return Ok(prefix),
+            }
+
+            remaining = r;
+        }
+    }
+
+    /// Create a builder, to construct a version from its individual parts without parsing.
+    ///
+    /// See [`VersionBuilder`] for more details.
+    pub fn builder() -> VersionBuilder {
+        VersionBuilder::default()
+    }
+
+    /// The dialect the version was parsed with.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let version = Version::parse("1.2.3", Dialect::Cargo)?;
+    /// assert_eq!(version.dialect(), Dialect::Cargo);
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn dialect(&self) -> Dialect {
+        self.dialect
+    }
+
+    /// The major, minor and patch versions, as a tuple.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let version = Version::parse("1.2.3-alpha", Dialect::Standard)?;
+    /// assert_eq!(version.core(), (1, 2, 3));
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn core(&self) -> (usize, usize, usize) {
+        (self.major, self.minor, self.patch)
+    }
+
+    /// The major, minor and patch versions with the prerelease identifier, as a tuple which can be
+    /// used as a key in ordered collections (like `BTreeMap`).
+    ///
+    /// Build metadata is deliberately excluded, as it doesn't affect precedence. The tuple is ordered
+    /// following the standard precedence rules, regardless of the dialect the version was parsed with.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let a = Version::parse("1.2.3-rc.1+build.1", Dialect::Standard)?;
+    /// let b = Version::parse("1.2.3-rc.1+build.2", Dialect::Standard)?;
+    /// assert_eq!(a.as_tuple(), b.as_tuple());
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn as_tuple(&self) -> (usize, usize, usize, Prerelease) {
+        (self.major, self.minor, self.patch, self.prerelease.clone())
+    }
+
+    /// The major, minor and patch versions, as a [`VersionCore`].
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let version = Version::parse("1.2.3-alpha", Dialect::Standard)?;
+    /// assert_eq!(version.core_version().to_string(), "1.2.3");
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn core_version(&self) -> VersionCore {
+        VersionCore {
+            major: self.major,
+            minor: self.minor,
+            patch: self.patch,
+        }
+    }
+
+    /// Whether two versions share the same major, minor and patch versions, ignoring any prerelease
+    /// identifier or build metadata.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let version = Version::parse("1.2.3-alpha", Dialect::Standard)?;
+    /// assert!(version.matches_core(&Version::parse("1.2.3", Dialect::Standard)?));
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn matches_core(&self, other: &Version) -> bool {
+        self.core() == other.core()
+    }
+
+    /// Whether two versions have the same precedence, meaning they have equal major, minor and patch
+    /// versions, and an equal prerelease identifier, following the version's dialect.
+    ///
+    /// Build metadata is [ignored when determining precedence](https://semver.org/spec/v2.0.0.html#spec-item-10),
+    /// so versions which only differ by their build metadata have the same precedence. This is the same
+    /// relation as the `==` operator, except for dialects where build metadata is significant (see
+    /// [`Version::cmp_precedence`]).
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let a = Version::parse("1.0.0+build.1", Dialect::Standard)?;
+    /// let b = Version::parse("1.0.0+build.2", Dialect::Standard)?;
+    /// assert!(a.same_precedence(&b));
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn same_precedence(&self, other: &Version) -> bool {
+        self.cmp_precedence(other) == Ordering::Equal
+    }
+
+    /// Write the version into any [`core::fmt::Write`] implementation, following the version's dialect.
+    ///
+    /// For dialects which reproduce the original version (like the standard dialect), this writes each
+    /// part directly without allocating a string. Dialects which normalize the version (like PEP 440)
+    /// still allocate while formatting.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let version = Version::parse("1.2.3-beta.1+build", Dialect::Standard)?;
+    ///
+    /// let mut string = String::new();
+    /// version.write_to(&mut string).unwrap();
+    /// assert_eq!(string, "1.2.3-beta.1+build");
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn write_to<W: core::fmt::Write>(&self, w: &mut W) -> core::fmt::Result {
+        self.dialect.write_to(self, w)
+    }
+
+    /// Compare the precedence of two versions, following the version's dialect.
+    ///
+    /// Build metadata never affects the result, so versions which only differ by their build metadata
+    /// (like `1.0.0+a` and `1.0.0+b`) are always [`Ordering::Equal`]. This is the same order as the
+    /// comparison operators, except for dialects which use the build metadata as a tie-break (like
+    /// Cargo), or treat it as part of the version (like a PEP 440 local version).
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use smvr::{Dialect, Version};
+    /// let a = Version::parse("1.0.0+a", Dialect::Standard)?;
+    /// let b = Version::parse("1.0.0+b", Dialect::Standard)?;
+    /// assert_eq!(a.cmp_precedence(&b), Ordering::Equal);
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn cmp_precedence(&self, other: &Version) -> Ordering {
+        if self.build_metadata == BuildMetadata::Empty
+            && other.build_metadata == BuildMetadata::Empty
+        {
+            return self.cmp(other);
+        }
+
+        // Some dialects order versions by their build metadata, so it has to be removed first
+        self.without_build_metadata()
+            .cmp(&other.without_build_metadata())
+    }
+
+    /// Compare the precedence of two versions, ranking known prerelease components with a custom
+    /// precedence table, rather than lexically.
+    ///
+    /// The table lists prerelease components from lowest to highest precedence (like
+    /// `["snapshot", "ea", "preview", "rc"]`). When two components are both listed, they're ordered by
+    /// their position in the table. Otherwise, the components follow the standard precedence rules, so
+    /// unlisted components fall back to lexical ordering. Build metadata never affects the result.
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use smvr::{Dialect, Version};
+    /// let snapshot = Version::parse("1.0.0-snapshot", Dialect::Standard)?;
+    /// let preview = Version::parse("1.0.0-preview", Dialect::Standard)?;
+    /// assert_eq!(snapshot.cmp(&preview), Ordering::Greater);
+    /// assert_eq!(
+    ///     snapshot.cmp_with_prerelease_ranks(&preview, &["snapshot", "preview", "rc"]),
+    ///     Ordering::Less
+    /// );
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn cmp_with_prerelease_ranks(&self, other: &Version, ranks: &[&str]) -> Ordering {
+        let rank = |component: &PrereleaseComponent| {
+            component
+                .as_str()
+                .and_then(|component| ranks.iter().position(|rank| *rank == component))
+        };
+
+        self.core()
+            .cmp(&other.core())
+            .then_with(|| match (&self.prerelease, &other.prerelease) {
+                (Prerelease::Identifier(a), Prerelease::Identifier(b)) => a
+                    .iter()
+                    .zip(b)
+                    .map(|(a, b)| match (rank(a), rank(b)) {
+                        (Some(a_rank), Some(b_rank)) => a_rank.cmp(&b_rank),
+                        _ => a.cmp(b),
+                    })
+                    .find(|ordering| *ordering != Ordering::Equal)
+                    .unwrap_or_else(|| a.len().cmp(&b.len())),
+                (a, b) => a.cmp(b),
+            })
+    }
+
+    /// The greater of two versions, following the version's dialect, or `self` when they're equal.
+    ///
+    /// Unlike [`Ord::max`], which returns `other` when the versions are equal, this keeps the
+    /// original version (so a version's build metadata is kept over an equal version's).
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let beta = Version::parse("1.0.0-beta", Dialect::Standard)?;
+    /// let stable = Version::parse("1.0.0", Dialect::Standard)?;
+    /// assert_eq!(beta.max(stable).to_string(), "1.0.0");
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn max(self, other: Version) -> Version {
+        match self.cmp(&other) {
+            Ordering::Less => other,
+            Ordering::Equal | Ordering::Greater => self,
+        }
+    }
+
+    /// The lesser of two versions, following the version's dialect, or `self` when they're equal.
+    ///
+    /// Like [`Ord::min`], this keeps the original version when the versions are equal.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let beta = Version::parse("1.0.0-beta", Dialect::Standard)?;
+    /// let stable = Version::parse("1.0.0", Dialect::Standard)?;
+    /// assert_eq!(beta.min(stable).to_string(), "1.0.0-beta");
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn min(self, other: Version) -> Version {
+        match self.cmp(&other) {
+            Ordering::Greater => other,
+            Ordering::Less | Ordering::Equal => self,
+        }
+    }
+
+    /// Every part of the version, in canonical order (major, minor, patch, prerelease and build
+    /// metadata).
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let version = Version::parse("1.2.3-rc.1+build", Dialect::Standard)?;
+    /// let parts = version.components();
+    /// assert_eq!((parts.major, parts.minor, parts.patch), (1, 2, 3));
+    /// assert_eq!(parts.build_metadata.components(), vec!["build"]);
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn components(&self) -> VersionParts<'_> {
+        VersionParts {
+            major: self.major,
+            minor: self.minor,
+            patch: self.patch,
+            prerelease: &self.prerelease,
+            build_metadata: &self.build_metadata,
+        }
+    }
+
+    /// Compare two versions following the ordering of a chosen dialect, regardless of the dialects
+    /// each version was parsed with.
+    ///
+    /// Versions from different dialects are otherwise ordered by their dialect, so this provides an
+    /// explicit way to reconcile them.
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use smvr::{Dialect, Version};
+    /// let standard = Version::parse("1.2.3", Dialect::Standard)?;
+    /// let cargo = Version::parse("1.2.4", Dialect::Cargo)?;
+    /// assert_eq!(standard.compare_as(&cargo, Dialect::Standard), Ordering::Less);
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn compare_as(&self, other: &Version, dialect: Dialect) -> Ordering {
+        dialect.compare(self, other)
+    }
+
+    /// Whether the version has a prerelease identifier (like `1.0.0-beta`).
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// assert!(Version::parse("1.0.0-beta", Dialect::Standard)?.is_prerelease());
+    /// assert!(!Version::parse("1.0.0", Dialect::Standard)?.is_prerelease());
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn is_prerelease(&self) -> bool {
+        matches!(self.prerelease, Prerelease::Identifier(_))
+    }
+
+    /// Whether the version is considered stable.
+    ///
+    /// A stable version has no prerelease identifier, and a major version of at least one. Versions
+    /// with a major version of zero (like `0.9.0`) are considered unstable, as they're reserved for
+    /// [initial development](https://semver.org/spec/v2.0.0.html#spec-item-4).
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// assert!(Version::parse("1.0.0", Dialect::Standard)?.is_stable());
+    /// assert!(!Version::parse("1.0.0-beta", Dialect::Standard)?.is_stable());
+    /// assert!(!Version::parse("0.9.0", Dialect::Standard)?.is_stable());
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn is_stable(&self) -> bool {
+        self.prerelease == Prerelease::Empty && !self.is_initial_development()
+    }
+
+    /// Whether the version is in initial development, because its major version is zero.
+    ///
+    /// SemVer treats these versions specially, as
+    /// [anything may change at any time](https://semver.org/spec/v2.0.0.html#spec-item-4), so the
+    /// public API should not be considered stable. Unlike [`Version::is_stable`], the prerelease
+    /// identifier is ignored.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// assert!(Version::parse("0.9.0", Dialect::Standard)?.is_initial_development());
+    /// assert!(!Version::parse("1.0.0", Dialect::Standard)?.is_initial_development());
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn is_initial_development(&self) -> bool {
+        self.major == 0
+    }
+
+    /// Whether the version is marked as `+incompatible`, which Go modules use for major versions (v2
+    /// and above) published without a `go.mod` file.
+    ///
+    /// This is only ever true for versions parsed using the Go dialect.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let version = Version::parse("v2.0.0+incompatible", Dialect::Go)?;
+    /// assert!(version.is_incompatible());
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn is_incompatible(&self) -> bool {
+        self.dialect == Go && dialect::Go::is_incompatible(self)
+    }
+
+    /// Increment the major version, resetting the minor and patch versions to zero.
+    ///
+    /// Any prerelease identifier and build metadata are cleared, following SemVer. Use
+    /// [`Version::bump_major_with`] to keep them.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let version = Version::parse("1.2.3-alpha+build", Dialect::Standard)?;
+    /// assert_eq!(version.bump_major().to_string(), "2.0.0");
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics when the major version is `usize::MAX`, or the version has no numeric parts (like the
+    /// Docker tag `latest`). Use [`Version::bump_major_checked`] to handle these cases instead.
+    pub fn bump_major(&self) -> Version {
+        Version::expect_bumped(self.bump_major_checked(), PartType::Major)
+    }
+
+    /// Increment the major version, resetting the minor and patch versions to zero, or return an
+    /// error when the major version would overflow or the version has no numeric parts.
+    ///
+    /// Any prerelease identifier and build metadata are cleared.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Error, PartType, Version};
+    /// let version = Version::parse("1.2.3", Dialect::Standard)?;
+    /// assert_eq!(version.bump_major_checked()?.to_string(), "2.0.0");
+    ///
+    /// let version = Version::builder().major(usize::MAX).build()?;
+    /// assert_eq!(version.bump_major_checked(), Err(Error::NumberOverflow(PartType::Major)));
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn bump_major_checked(&self) -> Result<Version, Error> {
+        let major = self
+            .major
+            .checked_add(1)
+            .ok_or(Error::NumberOverflow(PartType::Major))?;
+
+        self.bumped(major, 0, 0)
+    }
+
+    /// Increment the minor version, resetting the patch version to zero.
+    ///
+    /// Any prerelease identifier and build metadata are cleared, following SemVer. Use
+    /// [`Version::bump_minor_with`] to keep them.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let version = Version::parse("1.2.3-alpha+build", Dialect::Standard)?;
+    /// assert_eq!(version.bump_minor().to_string(), "1.3.0");
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics when the minor version is `usize::MAX`, or the version has no numeric parts (like the
+    /// Docker tag `latest`). Use [`Version::bump_minor_checked`] to handle these cases instead.
+    pub fn bump_minor(&self) -> Version {
+        Version::expect_bumped(self.bump_minor_checked(), PartType::Minor)
+    }
+
+    /// Increment the minor version, resetting the patch version to zero, or return an error when
+    /// the minor version would overflow or the version has no numeric parts.
+    ///
+    /// Any prerelease identifier and build metadata are cleared.
+    pub fn bump_minor_checked(&self) -> Result<Version, Error> {
+        let minor = self
+            .minor
+            .checked_add(1)
+            .ok_or(Error::NumberOverflow(PartType::Minor))?;
+
+        self.bumped(self.major, minor, 0)
+    }
+
+    /// Increment the patch version.
+    ///
+    /// Any prerelease identifier and build metadata are cleared, following SemVer. Use
+    /// [`Version::bump_patch_with`] to keep them.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let version = Version::parse("1.2.3-alpha+build", Dialect::Standard)?;
+    /// assert_eq!(version.bump_patch().to_string(), "1.2.4");
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics when the patch version is `usize::MAX`, or the version has no numeric parts (like the
+    /// Docker tag `latest`). Use [`Version::bump_patch_checked`] to handle these cases instead.
+    pub fn bump_patch(&self) -> Version {
+        Version::expect_bumped(self.bump_patch_checked(), PartType::Patch)
+    }
+
+    /// Increment the patch version, or return an error when the patch version would overflow or the
+    /// version has no numeric parts.
+    ///
+    /// Any prerelease identifier and build metadata are cleared.
+    pub fn bump_patch_checked(&self) -> Result<Version, Error> {
+        let patch = self
+            .patch
+            .checked_add(1)
+            .ok_or(Error::NumberOverflow(PartType::Patch))?;
+
+        self.bumped(self.major, self.minor, patch)
+    }
+
+    /// Increment the major version, resetting the minor and patch versions to zero, and keeping the
+    /// prerelease identifier or build metadata chosen by the options.
+    ///
+    /// An error is returned when the major version would overflow.
+    ///
+    /// ```
+    /// use smvr::{BumpOptions, Dialect, Version};
+    /// let version = Version::parse("1.2.3+sha.abc", Dialect::Standard)?;
+    /// let options = BumpOptions { keep_build_metadata: true, ..BumpOptions::default() };
+    /// assert_eq!(version.bump_major_with(options)?.to_string(), "2.0.0+sha.abc");
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn bump_major_with(&self, options: BumpOptions) -> Result<Version, Error> {
+        Ok(self.keep_bumped_parts(self.bump_major_checked()?, options))
+    }
+
+    /// Increment the minor version, resetting the patch version to zero, and keeping the prerelease
+    /// identifier or build metadata chosen by the options.
+    ///
+    /// An error is returned when the minor version would overflow.
+    pub fn bump_minor_with(&self, options: BumpOptions) -> Result<Version, Error> {
+        Ok(self.keep_bumped_parts(self.bump_minor_checked()?, options))
+    }
+
+    /// Increment the patch version, keeping the prerelease identifier or build metadata chosen by the
+    /// options.
+    ///
+    /// An error is returned when the patch version would overflow.
+    pub fn bump_patch_with(&self, options: BumpOptions) -> Result<Version, Error> {
+        Ok(self.keep_bumped_parts(self.bump_patch_checked()?, options))
+    }
+
+    /// Increment the version by the given level, or return an error when the incremented part would
+    /// overflow.
+    ///
+    /// This is the same as calling the matching `bump_*_checked` method, so any prerelease identifier
+    /// and build metadata are cleared.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Increment, Version};
+    /// let version = Version::parse("1.2.3", Dialect::Standard)?;
+    /// assert_eq!(version.apply_increment(Increment::Major)?.to_string(), "2.0.0");
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn apply_increment(&self, increment: Increment) -> Result<Version, Error> {
+        match increment {
+            Increment::Major => self.bump_major_checked(),
+            Increment::Minor => self.bump_minor_checked(),
+            Increment::Patch => self.bump_patch_checked(),
+        }
+    }
+
+    /// Create a version bumped from this one, with the given numeric parts.
+    ///
+    /// Any dialect-specific parts which still apply (like an epoch) are kept.
+    ///
+    /// Versions without numeric parts (like the Docker tag `latest`) can't be bumped, so return an
+    /// [`Error::MissingPart`] error for the major part.
+    fn bumped(&self, major: usize, minor: usize, patch: usize) -> Result<Version, Error> {
+        if !self.dialect.is_versioned(self) {
+            return Err(Error::MissingPart(PartType::Major));
+        }
+
+        let mut bumped = Version::new(major, minor, patch, None, None, self.prefix, self.dialect);
+        bumped.extension = self.dialect.retain_extension(self, &bumped);
+
+        Ok(bumped)
+    }
+
+    /// Unwrap a bumped version, panicking with a message describing why it couldn't be bumped.
+    fn expect_bumped(bumped: Result<Version, Error>, part: PartType) -> Version {
+        match bumped {
+            Ok(bumped) => bumped,
+            Err(Error::MissingPart(_)) => {
+                panic!("the version has no numeric parts to be incremented")
+            }
+            Err(_) => panic!("the {} version part is too large to be incremented", part),
+        }
+    }
+
+    /// Copy the parts chosen by the options onto a version bumped from this one.
+    fn keep_bumped_parts(&self, mut bumped: Version, options: BumpOptions) -> Version {
+        if options.keep_prerelease {
+            bumped.prerelease = self.prerelease.clone();
+        }
+
+        if options.keep_build_metadata {
+            bumped.build_metadata = self.build_metadata.clone();
+        }
+
+        bumped
+    }
+
+    /// Increment the last component of the prerelease identifier, when it's numeric.
+    ///
+    /// Earlier prerelease components and build metadata are preserved. No version is returned
+    /// when there is no prerelease identifier, the last component is not a number, or incrementing
+    /// it would overflow.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let version = Version::parse("1.0.0-rc.1", Dialect::Standard)?;
+    /// assert_eq!(version.increment_prerelease().unwrap().to_string(), "1.0.0-rc.2");
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn increment_prerelease(&self) -> Option<Version> {
+        let mut version = self.clone();
+
+        match &mut version.prerelease {
+            Prerelease::Identifier(components) => match components.last_mut() {
+                Some(PrereleaseComponent::Number(number)) => *number = number.checked_add(1)?,
+                _ => return None,
+            },
+            Prerelease::Empty => return None,
+        }
+
+        Some(version)
+    }
+
+    /// The most significant part which differs between two versions, or `None` when every part is
+    /// the same.
+    ///
+    /// Parts are checked in order of significance: major, minor, patch, prerelease and finally
+    /// build metadata. Parts specific to the version's dialect are reported as the part they're
+    /// ordered alongside, so an epoch (like PEP 440's `1!1.0`) is reported as the major version, and
+    /// any other part (like a NuGet revision) as the patch version.
+    ///
+    /// ```
+    /// use smvr::{Dialect, PartType, Version};
+    /// let version = Version::parse("1.2.3", Dialect::Standard)?;
+    /// assert_eq!(version.diff(&Version::parse("1.3.0", Dialect::Standard)?), Some(PartType::Minor));
+    /// assert_eq!(version.diff(&Version::parse("1.2.3", Dialect::Standard)?), None);
+    ///
+    /// let version = Version::parse("1.2.3.4", Dialect::NuGet)?;
+    /// assert_eq!(version.diff(&Version::parse("1.2.3.5", Dialect::NuGet)?), Some(PartType::Patch));
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn diff(&self, other: &Version) -> Option<PartType> {
+        let standard = if self.major != other.major {
+            Some(PartType::Major)
+        } else if self.minor != other.minor {
+            Some(PartType::Minor)
+        } else if self.patch != other.patch {
+            Some(PartType::Patch)
+        } else if self.prerelease != other.prerelease {
+            Some(PartType::Prerelease)
+        } else if self.build_metadata != other.build_metadata {
+            Some(PartType::BuildMetadata)
+        } else {
+            None
+        };
+
+        if self.dialect != other.dialect {
+            return standard;
+        }
+
+        standard
+            .into_iter()
+            .chain(self.dialect.diff(self, other))
+            .min()
+            // Any other parts specific to the dialect which differ (like a Debian revision)
+            .or_else(|| (self != other).then_some(PartType::Patch))
+    }
+
+    /// Whether the version is caret-compatible with a base version (as if matching `^base`).
+    ///
+    /// The left-most non-zero part of the base version may not change, so versions compatible with
+    /// `1.2.3` are `>=1.2.3, <2.0.0`, with `0.2.3` are `>=0.2.3, <0.3.0`, and with `0.0.3` are `>=0.0.3, <0.0.4`.
+    ///
+    /// Versions are compared following their dialect, and prereleases follow the same rules as
+    /// [`Requirement::matches`](crate::Requirement::matches).
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let base = Version::parse("1.2.0", Dialect::Standard)?;
+    /// assert!(Version::parse("1.4.2", Dialect::Standard)?.satisfies_caret(&base));
+    /// assert!(!Version::parse("2.0.0", Dialect::Standard)?.satisfies_caret(&base));
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn satisfies_caret(&self, base: &Version) -> bool {
+        Requirement::caret(base).is_ok_and(|requirement| requirement.matches(self))
+    }
+
+    /// Whether the version is tilde-compatible with a base version (as if matching `~base`).
+    ///
+    /// Only patch-level changes are allowed, so versions compatible with `1.2.3` are `>=1.2.3, <1.3.0`.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let base = Version::parse("1.2.3", Dialect::Standard)?;
+    /// assert!(Version::parse("1.2.9", Dialect::Standard)?.satisfies_tilde(&base));
+    /// assert!(!Version::parse("1.3.0", Dialect::Standard)?.satisfies_tilde(&base));
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn satisfies_tilde(&self, base: &Version) -> bool {
+        Requirement::tilde(base).is_ok_and(|requirement| requirement.matches(self))
+    }
+
+    /// Whether the version is a compatible replacement for another version, following the SemVer
+    /// public API rules (in other words, whether it's safe to upgrade from `other` to this version).
+    ///
+    /// Once the major version is at least one, versions with the same major version are compatible, so
+    /// long as this version isn't older. During [initial development](https://semver.org/spec/v2.0.0.html#spec-item-4)
+    /// (a major version of zero), the minor version must also be the same.
+    ///
+    /// Unlike [`Version::satisfies_caret`], prereleases aren't treated specially, and are compatible
+    /// following their precedence.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let version = Version::parse("1.4.0", Dialect::Standard)?;
+    /// assert!(version.is_compatible_with(&Version::parse("1.2.0", Dialect::Standard)?));
+    /// assert!(!version.is_compatible_with(&Version::parse("2.0.0", Dialect::Standard)?));
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn is_compatible_with(&self, other: &Version) -> bool {
+        let is_same_api = if self.major == 0 {
+            self.major == other.major && self.minor == other.minor
+        } else {
+            self.major == other.major
+        };
+
+        is_same_api && self >= other
+    }
+
+    /// A strictly normalized form of the version, suitable for use as a cache key for versions of equal
+    /// precedence.
+    ///
+    /// Versions from the same dialect with equal precedence (see [`Version::cmp_precedence`]) have the
+    /// same canonical form, and versions with different precedence never do. Most dialects follow
+    /// `major.minor.patch[-prerelease]`, while dialects with their own parts include them in their
+    /// normalized form (like the epoch in the PEP 440 version `2!1.0.0`).
+    ///
+    /// Unlike formatting the version (which faithfully reproduces the version following its dialect), build
+    /// metadata and dialect-specific prefixes are omitted.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let version = Version::parse("v1.2.3-beta.1+build", Dialect::Npm)?;
+    /// assert_eq!(version.to_string(), "v1.2.3-beta.1+build");
+    /// assert_eq!(version.canonicalize(), "1.2.3-beta.1");
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn canonicalize(&self) -> String {
+        self.dialect.canonicalize(self)
+    }
+
+    /// A copy of the version, with the prerelease identifier replaced by the components provided.
+    ///
+    /// The numeric parts, build metadata and dialect are preserved. An empty list of components
+    /// removes the prerelease identifier. Unlike [`Version::with_build_metadata`], the components are
+    /// not validated against the dialect, so use [`Version::builder`] when they come from untrusted input
+    /// (or check the result with [`Version::validate_fields`]).
+    ///
+    /// ```
+    /// use smvr::{Dialect, PrereleaseComponent, Version};
+    /// let version = Version::parse("1.2.3", Dialect::Standard)?;
+    /// let prerelease = version.with_prerelease(vec![
+    ///     PrereleaseComponent::String("rc".to_string()),
+    ///     PrereleaseComponent::Number(1),
+    /// ]);
+    /// assert_eq!(prerelease.to_string(), "1.2.3-rc.1");
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn with_prerelease(&self, components: Vec<PrereleaseComponent>) -> Version {
+        let mut version = self.clone();
+        version.prerelease = if components.is_empty() {
+            Prerelease::Empty
+        } else {
+            Prerelease::Identifier(components)
+        };
+
+        version
+    }
+
+    /// A copy of the version, with the build metadata replaced.
+    ///
+    /// The build metadata is validated against the rules of the version's dialect. The numeric parts,
+    /// prerelease identifier and dialect are preserved.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let version = Version::parse("1.2.3", Dialect::Standard)?;
+    /// assert_eq!(version.with_build_metadata("sha.abc")?.to_string(), "1.2.3+sha.abc");
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn with_build_metadata(&self, build_metadata: &str) -> Result<Version, Error> {
+        Version::validate_part(
+            build_metadata.as_bytes(),
+            PartType::BuildMetadata,
+            self.dialect,
+        )?;
+
+        let mut version = self.clone();
+        version.build_metadata = BuildMetadata::Identifier(build_metadata.to_string());
+
+        Ok(version)
+    }
+
+    /// Store every numeric prerelease component as a number, so equivalent representations of the
+    /// same prerelease identifier compare as equal.
+    ///
+    /// Parsing always stores numeric components as numbers, but a prerelease identifier built by hand
+    /// may store them as strings (like `String("01")`), which would be compared lexically. String
+    /// components made up entirely of digits are converted into numbers, dropping any leading zeros.
+    /// Components too large to be represented as a number are left unchanged.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Prerelease, PrereleaseComponent, Version};
+    /// let mut version = Version::parse("1.0.0", Dialect::Standard)?;
+    /// version.prerelease = Prerelease::Identifier(vec![
+    ///     PrereleaseComponent::String("alpha".to_string()),
+    ///     PrereleaseComponent::String("00".to_string()),
+    /// ]);
+    ///
+    /// version.normalize_prerelease();
+    /// assert_eq!(version, Version::parse("1.0.0-alpha.0", Dialect::Standard)?);
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn normalize_prerelease(&mut self) {
+        if let Prerelease::Identifier(components) = &mut self.prerelease {
+            for component in components {
+                let number = match component {
+                    PrereleaseComponent::String(string)
+                        if !string.is_empty()
+                            && string.bytes().all(|byte| byte.is_ascii_digit()) =>
+                    {
+                        string.parse().ok()
+                    }
+                    _ => None,
+                };
+
+                if let Some(number) = number {
+                    *component = PrereleaseComponent::Number(number);
+                }
+            }
+        }
+    }
+
+    /// Check the current prerelease identifier and build metadata are still valid, following the
+    /// version's dialect.
+    ///
+    /// The fields of a version are public, so they can be changed into values which would never have
+    /// been parsed (like a prerelease component containing a space). This re-validates them, so they
+    /// can be trusted again after being changed. The position of an [`Error::InvalidCharacter`] error
+    /// is relative to the start of the offending prerelease component, or build metadata.
+    ///
+    /// ```
+    /// use smvr::{Dialect, PrereleaseComponent, Prerelease, Version};
+    /// let mut version = Version::parse("1.2.3-rc.1", Dialect::Standard)?;
+    /// assert!(version.validate_fields().is_ok());
+    ///
+    /// version.prerelease = Prerelease::Identifier(vec![PrereleaseComponent::String("r c".to_string())]);
+    /// assert!(version.validate_fields().is_err());
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn validate_fields(&self) -> Result<(), Error> {
+        if let Prerelease::Identifier(components) = &self.prerelease {
+            if components.is_empty() {
+                return Err(Error::EmptyPart(PartType::Prerelease));
+            }
+
+            for component in components {
+                if let PrereleaseComponent::String(component) = component {
+                    Version::validate_part(
+                        component.as_bytes(),
+                        PartType::Prerelease,
+                        self.dialect,
+                    )?;
+                }
+            }
+        }
+
+        if let BuildMetadata::Identifier(build_metadata) = &self.build_metadata {
+            Version::validate_part(
+                build_metadata.as_bytes(),
+                PartType::BuildMetadata,
+                self.dialect,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// A copy of the version, with the build metadata removed.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let version = Version::parse("1.2.3-rc.1+build", Dialect::Standard)?;
+    /// assert_eq!(version.without_build_metadata().to_string(), "1.2.3-rc.1");
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn without_build_metadata(&self) -> Version {
+        let mut version = self.clone();
+        version.build_metadata = BuildMetadata::Empty;
+
+        version
+    }
+
+    /// A copy of the version, with the prerelease identifier removed.
+    ///
+    /// ```
+    /// use smvr::{Dialect, Version};
+    /// let version = Version::parse("1.2.3-rc.1+build", Dialect::Standard)?;
+    /// assert_eq!(version.without_prerelease().to_string(), "1.2.3+build");
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn without_prerelease(&self) -> Version {
+        let mut version = self.clone();
+        version.prerelease = Prerelease::Empty;
+
+        version
+    }
+
+    /// A copy of the version, keeping every part up to (and including) the given part, and clearing
+    /// the parts after it.
+    ///
+    /// Numeric parts after the given part are reset to zero, so truncating `1.2.3-rc.1` to the minor
+    /// part gives `1.2.0`, and to the major part gives `1.0.0`. This is useful for grouping versions
+    /// by their major or minor release line.
+    ///
+    /// Truncating to the prerelease only clears the build metadata, and truncating to the build
+    /// metadata leaves the version unchanged. Any dialect-specific parts which still apply (like an
+    /// epoch) are kept, and versions without numeric parts (like the Docker tag `latest`) are left
+    /// unchanged.
+    ///
+    /// ```
+    /// use smvr::{Dialect, PartType, Version};
+    /// let version = Version::parse("1.2.3-rc.1+build", Dialect::Standard)?;
+    /// assert_eq!(version.truncate_to(PartType::Major).to_string(), "1.0.0");
+    /// assert_eq!(version.truncate_to(PartType::Minor).to_string(), "1.2.0");
+    /// assert_eq!(version.truncate_to(PartType::Patch).to_string(), "1.2.3");
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    pub fn truncate_to(&self, part: PartType) -> Version {
+        if !self.dialect.is_versioned(self) {
+            return self.clone();
+        }
+
+        let (minor, patch) = match part {
+            PartType::Major => (0, 0),
+            PartType::Minor => (self.minor, 0),
+            PartType::Patch => (self.minor, self.patch),
+            PartType::Prerelease => return self.without_build_metadata(),
+            PartType::BuildMetadata => return self.clone(),
+        };
+
+        let mut truncated = Version::new(
+            self.major,
+            minor,
+            patch,
+            None,
+            None,
+            self.prefix,
+            self.dialect,
+        );
+        truncated.extension = self.dialect.retain_extension(self, &truncated);
+
+        truncated
+    }
+
+    /// Progressively parse and return one particular part of a version string.
+    ///
+    /// The end point of a part is determined by the chosen dialect.
+    ///
+    /// With each byte parsed the dialect decides whether:
+    /// 1. The byte is valid inside the part (i.e. it's a digit when inside the minor part of a
+    ///    version string).
+    /// 2. Whether to consume the byte, or include
+    ///
+    /// The captured part is returned as a slice of the provided bytes, so no allocations are made.
+    ///
+    /// When a collection of errors is provided, invalid bytes are added to it rather than being returned.
+    ///
+    /// The offset is the position of the first byte inside the full version string, so that errors
+    /// can report where they occurred.
+    fn parse_part<'a>(
+        version_bytes: &'a [u8],
+        offset: usize,
+        dialect: Dialect,
+        current_part: PartType,
+        mut errors: Option<&mut Vec<Error>>,
+    ) -> Result<(&'a [u8], &'a RemainingUnparsedBytes, NextPartType), Error> {
+        for (i, byte) in version_bytes.iter().enumerate() {
+            let part = (current_part, &version_bytes[..i]);
+            let remaining = &version_bytes[i + 1..];
+
+            let next_part = dialect.parse_byte(byte, offset + i, part, remaining);
+
+            // When collecting errors, an invalid byte is treated as part of the current part
+            let next_part = match (next_part, errors.as_deref_mut()) {
+                (Err(error), Some(errors)) => {
+                    errors.push(error);
+                    None
+                }
+                (next_part, _) => next_part?,
+            };
+
+            if next_part.is_some() {
+                return Ok((&version_bytes[..i], remaining, next_part));
+            }
+        }
+
+        Ok((version_bytes, &[], None))
+    }
+
+    /// Whether the bytes captured for a part are all digits.
+    fn is_numeric(bytes: &[u8]) -> bool {
+        bytes.iter().all(|i| (&b'0'..=&b'9').contains(&i))
+    }
+
+    /// Convert the bytes captured for a numeric part into a number.
+    ///
+    /// Parts which were not provided default to zero.
+    fn parse_number(bytes: &[u8], part: PartType) -> Result<usize, Error> {
+        if bytes.is_empty() {
+            return Ok(0);
+        }
+
+        alloc::str::from_utf8(bytes)
+            .map_err(|_| Error::InvalidUtf8(part))?
+            .parse::<usize>()
+            .map_err(|_| Error::NumberOverflow(part))
+    }
+
+    /// Convert the bytes captured for a numeric prerelease identifier into a number.
+    ///
+    /// An identifier is only known to be numeric once it's complete (`0a` is a valid alphanumeric
+    /// identifier), so leading zeros are rejected here rather than while parsing each byte.
+    fn parse_prerelease_number(bytes: &[u8]) -> Result<usize, Error> {
+        if bytes.len() > 1 && bytes[0] == b'0' {
+            return Err(Error::InvalidPrecedingZero(PartType::Prerelease));
+        }
+
+        Version::parse_number(bytes, PartType::Prerelease)
+    }
+
+    /// Convert the bytes captured for a single prerelease identifier into a component, detecting
+    /// whether it's numeric.
+    pub(crate) fn parse_prerelease_component(bytes: &[u8]) -> Result<PrereleaseComponent, Error> {
+        if Version::is_numeric(bytes) {
+            Ok(PrereleaseComponent::Number(
+                Version::parse_prerelease_number(bytes)?,
+            ))
+        } else {
+            Ok(PrereleaseComponent::String(Version::parse_string(
+                bytes,
+                PartType::Prerelease,
+            )?))
+        }
+    }
+
+    /// Convert the bytes captured for a string part into an owned string.
+    fn parse_string(bytes: &[u8], part: PartType) -> Result<String, Error> {
+        Ok(alloc::str::from_utf8(bytes)
+            .map_err(|_| Error::InvalidUtf8(part))?
+            .to_string())
+    }
+
+    /// Check the bytes of a single part are valid inside that part, following the dialect.
+    ///
+    /// The bytes must make up exactly one complete part, so any byte which would transition to
+    /// a different part is considered invalid.
+    pub(crate) fn validate_part(
+        bytes: &[u8],
+        part: PartType,
+        dialect: Dialect,
+    ) -> Result<(), Error> {
+        if Version::is_empty_part(bytes, part) {
+            return Err(Error::EmptyPart(part));
+        }
+
+        let (_, remaining, next_part) = Version::parse_part(bytes, 0, dialect, part, None)?;
+
+        if next_part.is_some() {
+            let position = bytes.len() - remaining.len() - 1;
+
+            return Err(Error::InvalidCharacter {
+                part,
+                byte: bytes[position],
+                position,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Whether the bytes captured for a part are empty, or (for build metadata, which is captured as a
+    /// whole) contain an empty dot-separated identifier.
+    fn is_empty_part(bytes: &[u8], part: PartType) -> bool {
+        match part {
+            PartType::BuildMetadata => bytes
+                .split(|byte| *byte == b'.')
+                .any(|identifier| identifier.is_empty()),
+            _ => bytes.is_empty(),
+        }
+    }
+
+    /// Create a new Version instance, using pre-parsed SemVer content.
+    pub(crate) fn new(
+        major: usize,
+        minor: usize,
+        patch: usize,
+        prerelease: Option<Vec<PrereleaseComponent>>,
+        build_metadata: Option<String>,
+        prefix: Option<char>,
+        dialect: Dialect,
+    ) -> Version {
+        Version {
+            major,
+            minor,
+            patch,
+            prerelease: if let Some(prerelease) = prerelease {
+                Prerelease::Identifier(prerelease)
+            } else {
+                Prerelease::Empty
+            },
+            build_metadata: if let Some(metadata) = build_metadata {
+                BuildMetadata::Identifier(metadata)
+            } else {
+                BuildMetadata::Empty
+            },
+            prefix,
+            dialect,
+            extension: None,
+        }
+    }
+}
+
+impl PartialEq for Version {
+    fn eq(&self, other: &Self) -> bool {
+        if self.dialect != other.dialect {
+            // Only versions originating from the same dialect can be compared. This
+            // prevents issues with inconsistent comparisons based on comparator order
+            return false;
+        }
+
+        self.dialect.equals(self, other)
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compare a version against a version string, which is parsed using the version's dialect.
+///
+/// A version string which fails to parse is never equal to the version.
+impl PartialEq<&str> for Version {
+    fn eq(&self, other: &&str) -> bool {
+        match Version::parse(other, self.dialect) {
+            Ok(other) => self.eq(&other),
+            Err(_) => false,
+        }
+    }
+}
+
+/// Order a version against a version string, which is parsed using the version's dialect.
+///
+/// A version string which fails to parse can't be ordered, so `partial_cmp` returns `None` (and
+/// every comparison operator returns `false`).
+///
+/// ```
+/// use smvr::{Dialect, Version};
+/// let version = Version::parse("1.2.3", Dialect::Standard)?;
+/// assert!(version < "2.0.0");
+/// assert!(!(version < "not-a-version"));
+/// # Ok::<(), smvr::Error>(())
+/// ```
+impl PartialOrd<&str> for Version {
+    fn partial_cmp(&self, other: &&str) -> Option<Ordering> {
+        Version::parse(other, self.dialect)
+            .ok()
+            .map(|other| self.cmp(&other))
+    }
+}
+
+impl Eq for Version {}
+
+/// A total order over versions, suitable for sorting or use in ordered collections (like `BTreeSet`).
+///
+/// Versions parsed with the same dialect are ordered following that dialect's rules.
+///
+/// Versions parsed with different dialects are never equal, and are instead ordered by their dialect, in
+/// the order the dialects are declared in [`Dialect`]. This fallback only exists to make the order total, and
+/// does not imply any relationship between the versions themselves.
+///
+/// ```
+/// use smvr::{Dialect, Version};
+///
+/// let mut versions = vec![
+///     Version::parse("1.0.0", Dialect::Standard)?,
+///     Version::parse("1.0.0-beta", Dialect::Standard)?,
+///     Version::parse("0.9.1", Dialect::Standard)?,
+/// ];
+///
+/// versions.sort();
+/// # assert_eq!(versions[0].to_string(), "0.9.1");
+/// # assert_eq!(versions[1].to_string(), "1.0.0-beta");
+/// # assert_eq!(versions[2].to_string(), "1.0.0");
+/// # Ok::<(), smvr::Error>(())
+/// ```
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.dialect != other.dialect {
+            // Only versions originating from the same dialect can be compared. To keep the order
+            // total, versions from different dialects fall back to being ordered by their dialect
+            return self.dialect.cmp(&other.dialect);
+        }
+
+        self.compare_as(other, self.dialect)
+    }
+}
+
+/// The zero version (`0.0.0`), following the standard dialect.
+///
+/// ```
+/// use smvr::Version;
+/// assert_eq!(Version::default().to_string(), "0.0.0");
+/// ```
+impl Default for Version {
+    fn default() -> Self {
+        Version::new(0, 0, 0, None, None, None, Standard)
+    }
+}
+
+/// Hash a version consistently with its equality.
+///
+/// Build metadata is deliberately excluded, as it does not contribute to the precedence of a version, and so
+/// two versions which only differ by their build metadata will produce the same hash. Dialects with their
+/// own equality (like RubyGems, where `1.pre` == `1.pre.0`) hash the parts they compare.
+impl Hash for Version {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.dialect.hash(state);
+        self.dialect.hash_version(self, state);
+    }
+}
+
+impl Display for Version {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.write_to(f)
+    }
+}
+
+impl FromStr for Version {
+    type Err = Error;
+
+    /// Parse a string into a Version instance, following the standard dialect (the same as
+    /// [`Version::parse_default`]).
+    ///
+    /// ```
+    /// use smvr::Version;
+    /// let version: Version = "1.2.3-alpha.1".parse()?;
+    /// # assert_eq!(version.to_string(), "1.2.3-alpha.1".to_string());
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    fn from_str(version: &str) -> Result<Self, Self::Err> {
+        Version::parse(version, Standard)
+    }
+}
+
+impl TryFrom<&str> for Version {
+    type Error = Error;
+
+    /// Parse a string into a Version instance, following the standard dialect (the same as
+    /// [`Version::parse_default`]).
+    ///
+    /// ```
+    /// use smvr::Version;
+    /// let version: Version = "1.2.3".try_into()?;
+    /// # assert_eq!(version.to_string(), "1.2.3".to_string());
+    /// # Ok::<(), smvr::Error>(())
+    /// ```
+    fn try_from(version: &str) -> Result<Self, Self::Error> {
+        Version::parse(version, Standard)
+    }
+}
+
+impl TryFrom<String> for Version {
+    type Error = Error;
+
+    /// Parse a string into a Version instance, following the standard dialect (the same as
+    /// [`Version::parse_default`]).
+    fn try_from(version: String) -> Result<Self, Self::Error> {
+        Version::parse(&version, Standard)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeMap;
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::cmp::Ordering;
+
+    use proptest::prelude::*;
+
+    use crate::dialect::Dialect;
+    use crate::error::Error;
+    use crate::version::{BuildMetadata, PartType, Prerelease, PrereleaseComponent, Version};
+
+    #[test]
+    fn parsing_minimal_version_with_standard_dialect() {
+        let version = Version::parse("1", Dialect::Standard).unwrap();
+
+        assert_eq!(version.major, 1);
+        assert_eq!(version.minor, 0);
+        assert_eq!(version.patch, 0);
+    }
+
+    #[test]
+    fn parsing_only_version_with_standard_dialect() {
+        let version = Version::parse("1.2.2", Dialect::Standard).unwrap();
+
+        assert_eq!(version.major, 1);
+        assert_eq!(version.minor, 2);
+        assert_eq!(version.patch, 2);
+    }
+
+    #[test]
+    fn parsing_version_with_standard_dialect() {
+        let version = Version::parse("12.19.1-alpha.12+build1234", Dialect::Standard).unwrap();
+
+        assert_eq!(version.major, 12);
+        assert_eq!(version.minor, 19);
+        assert_eq!(version.patch, 1);
+        assert_eq!(
+            version.prerelease,
+            Prerelease::Identifier(vec![
+                PrereleaseComponent::String("alpha".to_string()),
+                PrereleaseComponent::Number(12)
+            ])
+        );
+        assert_eq!(
+            version.build_metadata,
+            BuildMetadata::Identifier("build1234".to_string())
+        );
+
+        assert_eq!("12.19.1-alpha.12+build1234", version.to_string())
+    }
+
+    #[test]
+    fn parsing_version_with_proceeding_major_zero_standard_dialect() {
+        let version = Version::parse("0.1.0", Dialect::Standard).unwrap();
+
+        assert_eq!(version.major, 0);
+        assert_eq!(version.minor, 1);
+        assert_eq!(version.patch, 0);
+    }
+
+    #[test]
+    fn parsing_version_with_only_build_metadata_standard_dialect() {
+        let version = Version::parse("0.1.12+build.1234", Dialect::Standard).unwrap();
+
+        assert_eq!(version.major, 0);
+        assert_eq!(version.minor, 1);
+        assert_eq!(version.patch, 12);
+        assert_eq!(version.prerelease, Prerelease::Empty);
+        assert_eq!(
+            version.build_metadata,
+            BuildMetadata::Identifier("build.1234".to_string())
+        )
+    }
+
+    #[test]
+    fn parsing_version_with_proceeding_zero_standard_dialect() {
+        let version = Version::parse("12.019.1", Dialect::Standard);
+
+        let Err(error) = version else {
+            panic!("Parsing should have returned an error")
+        };
+
+        assert_eq!(error, Error::InvalidPrecedingZero(PartType::Minor))
+    }
+
+    #[test]
+    fn parsing_version_from_str_with_standard_dialect() {
+        let version: Version = "1.2.3-alpha.1".parse().unwrap();
+
+        assert_eq!(
+            version,
+            Version::parse("1.2.3-alpha.1", Dialect::Standard).unwrap()
+        );
+        assert_eq!(
+            "1.02.3".parse::<Version>(),
+            Err(Error::InvalidPrecedingZero(PartType::Minor))
+        );
+    }
+
+    #[test]
+    fn cloning_version_with_standard_dialect() {
+        let version = Version::parse("1.2.3-alpha.1+build.5", Dialect::Standard).unwrap();
+        let cloned = version.clone();
+
+        assert_eq!(cloned, version);
+        assert_eq!(cloned.prerelease, version.prerelease);
+        assert_eq!(cloned.build_metadata, version.build_metadata);
+        assert_eq!(cloned.to_string(), "1.2.3-alpha.1+build.5");
+    }
+
+    #[test]
+    fn sorting_versions_with_standard_dialect() {
+        let mut versions = vec![
+            Version::parse("1.0.0", Dialect::Standard).unwrap(),
+            Version::parse("1.0.0-beta.11", Dialect::Standard).unwrap(),
+            Version::parse("1.0.0-alpha", Dialect::Standard).unwrap(),
+            Version::parse("1.0.0-rc.1", Dialect::Standard).unwrap(),
+            Version::parse("0.9.9", Dialect::Standard).unwrap(),
+            Version::parse("1.0.0-beta.2", Dialect::Standard).unwrap(),
+            Version::parse("1.0.0-alpha.1", Dialect::Standard).unwrap(),
+            Version::parse("1.0.0-alpha.beta", Dialect::Standard).unwrap(),
+            Version::parse("1.0.0-beta", Dialect::Standard).unwrap(),
+        ];
+
+        versions.sort();
+
+        assert_eq!(
+            versions.iter().map(|v| v.to_string()).collect::<Vec<_>>(),
+            vec![
+                "0.9.9",
+                "1.0.0-alpha",
+                "1.0.0-alpha.1",
+                "1.0.0-alpha.beta",
+                "1.0.0-beta",
+                "1.0.0-beta.2",
+                "1.0.0-beta.11",
+                "1.0.0-rc.1",
+                "1.0.0"
+            ]
+        );
+    }
+
+    #[test]
+    fn sorting_versions_across_dialects() {
+        let mut versions = [
+            Version::parse("0.1.0", Dialect::Cargo).unwrap(),
+            Version::parse("2.0.0", Dialect::Standard).unwrap(),
+            Version::parse("1.0.0", Dialect::Cargo).unwrap(),
+            Version::parse("1.0.0", Dialect::Standard).unwrap(),
+        ];
+
+        versions.sort();
+
+        assert_eq!(
+            versions[0],
+            Version::parse("1.0.0", Dialect::Standard).unwrap()
+        );
+        assert_eq!(
+            versions[1],
+            Version::parse("2.0.0", Dialect::Standard).unwrap()
+        );
+        assert_eq!(
+            versions[2],
+            Version::parse("0.1.0", Dialect::Cargo).unwrap()
+        );
+        assert_eq!(
+            versions[3],
+            Version::parse("1.0.0", Dialect::Cargo).unwrap()
+        );
+    }
+
+    #[test]
+    fn hashing_versions_ignores_build_metadata() {
+        extern crate std;
+
+        use std::collections::HashSet;
+
+        let mut versions = HashSet::new();
+
+        versions.insert(Version::parse("1.2.3+build1", Dialect::Standard).unwrap());
+        versions.insert(Version::parse("1.2.3+build2", Dialect::Standard).unwrap());
+
+        assert_eq!(versions.len(), 1);
+
+        versions.insert(Version::parse("1.2.3", Dialect::Cargo).unwrap());
+        versions.insert(Version::parse("1.2.3-alpha", Dialect::Standard).unwrap());
+
+        assert_eq!(versions.len(), 3);
+    }
+
+    #[test]
+    fn parsing_non_ascii_version_with_standard_dialect() {
+        let version = Version::parse("1.\u{FF}.3", Dialect::Standard);
+
+        assert_eq!(
+            version,
+            Err(Error::InvalidCharacter {
+                part: PartType::Minor,
+                byte: 0xc3,
+                position: 2
+            })
+        );
+    }
+
+    #[test]
+    fn converting_invalid_utf8_parts() {
+        assert_eq!(
+            Version::parse_number(b"\xFF", PartType::Minor),
+            Err(Error::InvalidUtf8(PartType::Minor))
+        );
+        assert_eq!(
+            Version::parse_string(b"alpha\xFF", PartType::Prerelease),
+            Err(Error::InvalidUtf8(PartType::Prerelease))
+        );
+    }
+
+    #[test]
+    fn parsing_version_with_overflowing_major_standard_dialect() {
+        let version = Version::parse("99999999999999999999999999.0.0", Dialect::Standard);
+
+        assert_eq!(version, Err(Error::NumberOverflow(PartType::Major)));
+    }
+
+    #[test]
+    fn parsing_version_with_overflowing_prerelease_standard_dialect() {
+        let version = Version::parse("1.0.0-alpha.99999999999999999999999999", Dialect::Standard);
+
+        assert_eq!(version, Err(Error::NumberOverflow(PartType::Prerelease)));
+    }
+
+    #[test]
+    fn parsing_empty_version_with_standard_dialect() {
+        let version = Version::parse("", Dialect::Standard);
+
+        assert_eq!(version, Err(Error::EmptyPart(PartType::Major)));
+    }
+
+    #[test]
+    fn parsing_version_with_empty_minor_standard_dialect() {
+        let version = Version::parse("1..3", Dialect::Standard);
+
+        assert_eq!(version, Err(Error::EmptyPart(PartType::Minor)));
+    }
+
+    #[test]
+    fn parsing_version_with_empty_patch_standard_dialect() {
+        let version = Version::parse("1.2.", Dialect::Standard);
+
+        assert_eq!(version, Err(Error::EmptyPart(PartType::Patch)));
+    }
+
+    #[test]
+    fn parsing_version_with_extra_part_standard_dialect() {
+        let version = Version::parse("1.2.3.4", Dialect::Standard);
+
+        assert_eq!(
+            version,
+            Err(Error::InvalidCharacter {
+                part: PartType::Patch,
+                byte: b'.',
+                position: 5
+            })
+        );
+    }
+
+    #[test]
+    fn bumping_version_with_standard_dialect() {
+        let version = Version::parse("1.2.3-alpha+b", Dialect::Standard).unwrap();
+
+        assert_eq!(
+            version.bump_major(),
+            Version::parse("2.0.0", Dialect::Standard).unwrap()
+        );
+        assert_eq!(
+            version.bump_minor(),
+            Version::parse("1.3.0", Dialect::Standard).unwrap()
+        );
+        assert_eq!(
+            version.bump_patch(),
+            Version::parse("1.2.4", Dialect::Standard).unwrap()
+        );
+        assert_eq!(version.bump_minor().build_metadata, BuildMetadata::Empty);
+    }
+
+    #[test]
+    fn bumping_version_preserves_dialect() {
+        let version = Version::parse("v1.2.3-alpha", Dialect::Npm).unwrap();
+
+        assert_eq!(
+            version.bump_patch(),
+            Version::parse("1.2.4", Dialect::Npm).unwrap()
+        );
+        assert_eq!(version.bump_patch().to_string(), "v1.2.4");
+    }
+
+    #[test]
+    fn checking_stability_with_standard_dialect() {
+        let initial_development = Version::parse("0.9.0", Dialect::Standard).unwrap();
+        let stable = Version::parse("1.0.0", Dialect::Standard).unwrap();
+        let prerelease = Version::parse("1.0.0-beta", Dialect::Standard).unwrap();
+
+        assert!(!initial_development.is_prerelease());
+        assert!(!initial_development.is_stable());
+
+        assert!(!stable.is_prerelease());
+        assert!(stable.is_stable());
+
+        assert!(prerelease.is_prerelease());
+        assert!(!prerelease.is_stable());
+    }
+
+    #[test]
+    fn matching_core_with_standard_dialect() {
+        let prerelease = Version::parse("1.2.3-alpha", Dialect::Standard).unwrap();
+        let release = Version::parse("1.2.3+build", Dialect::Standard).unwrap();
+
+        assert_eq!(prerelease.core(), (1, 2, 3));
+        assert!(prerelease.matches_core(&release));
+        assert_ne!(prerelease, release);
+
+        assert!(!prerelease.matches_core(&Version::parse("1.2.4", Dialect::Standard).unwrap()));
+    }
+
+    #[test]
+    fn removing_build_metadata_and_prerelease_with_standard_dialect() {
+        let version = Version::parse("1.2.3-rc.1+build", Dialect::Standard).unwrap();
+
+        assert_eq!(version.without_build_metadata().to_string(), "1.2.3-rc.1");
+        assert_eq!(version.without_prerelease().to_string(), "1.2.3+build");
+        assert_eq!(
+            version.without_prerelease().without_build_metadata(),
+            Version::parse("1.2.3", Dialect::Standard).unwrap()
+        );
+    }
+
+    #[test]
+    fn removing_prerelease_preserves_dialect() {
+        let version = Version::parse("v1.2.3-rc.1", Dialect::Npm).unwrap();
+
+        assert_eq!(version.without_prerelease().to_string(), "v1.2.3");
+        assert_eq!(version.without_prerelease().dialect, Dialect::Npm);
+    }
+
+    #[test]
+    fn converting_version_from_strings_with_standard_dialect() {
+        let from_str = Version::try_from("1.2.3-alpha").unwrap();
+        let from_string = Version::try_from("1.2.3-alpha".to_string()).unwrap();
+
+        assert_eq!(
+            from_str,
+            Version::parse("1.2.3-alpha", Dialect::Standard).unwrap()
+        );
+        assert_eq!(from_string, from_str);
+
+        assert_eq!(
+            Version::try_from("1.2.a"),
+            Err(Error::InvalidCharacter {
+                part: PartType::Patch,
+                byte: b'a',
+                position: 4
+            })
+        );
+        assert_eq!(
+            Version::try_from("01.2.3".to_string()),
+            Err(Error::InvalidPrecedingZero(PartType::Major))
+        );
+    }
+
+    #[test]
+    fn reading_dialect_with_standard_dialect() {
+        let version = Version::parse("1.2.3", Dialect::Standard).unwrap();
+
+        assert_eq!(version.dialect(), Dialect::Standard);
+    }
+
+    #[test]
+    fn reporting_error_position_with_standard_dialect() {
+        let invalid_major = Version::parse("a.1.0", Dialect::Standard).unwrap_err();
+        let invalid_patch = Version::parse("1.2.x", Dialect::Standard).unwrap_err();
+
+        assert_eq!(
+            invalid_major,
+            Error::InvalidCharacter {
+                part: PartType::Major,
+                byte: b'a',
+                position: 0
+            }
+        );
+        assert_eq!(
+            invalid_patch,
+            Error::InvalidCharacter {
+                part: PartType::Patch,
+                byte: b'x',
+                position: 4
+            }
+        );
+
+        assert_eq!(
+            invalid_major.to_string(),
+            "the major version part contains an invalid character 'a' at byte 0"
+        );
+        assert_eq!(
+            invalid_patch.to_string(),
+            "the patch version part contains an invalid character 'x' at byte 4"
+        );
+    }
+
+    #[test]
+    fn comparing_versions_across_dialects_as_standard_dialect() {
+        let standard = Version::parse("1.2.3+build.2", Dialect::Standard).unwrap();
+        let cargo = Version::parse("1.2.3+build.1", Dialect::Cargo).unwrap();
+
+        assert_ne!(standard, cargo);
+        assert_eq!(standard.cmp(&cargo), Ordering::Less);
+
+        assert_eq!(
+            standard.compare_as(&cargo, Dialect::Standard),
+            Ordering::Equal
+        );
+        assert_eq!(
+            standard.compare_as(&cargo, Dialect::Cargo),
+            Ordering::Greater
+        );
+        assert_eq!(cargo.compare_as(&standard, Dialect::Cargo), Ordering::Less);
+    }
+
+    #[test]
+    fn validating_versions_with_standard_dialect() {
+        for version in [
+            "0.0.0",
+            "1",
+            "1.2",
+            "10.2.1-alpha.1+build-1",
+            "1.0.0-alpha.beta.1",
+            "1.0.0+build.1",
+            "",
+            "1.",
+            "1..2",
+            "1.01.0",
+            "1.2.3.4",
+            "a.1.0",
+            "1.2.x",
+            "1.2.3-al%pha",
+            "1.2.3+bu%ild",
+            "1.2.3-alpha.99999999999999999999999",
+            "99999999999999999999999.0.0",
+            "1.ß.0",
+        ] {
+            assert_eq!(
+                Version::validate(version, Dialect::Standard),
+                Version::parse(version, Dialect::Standard).map(|_| ()),
+                "{}",
+                version
+            );
+        }
+    }
+
+    #[test]
+    fn validating_versions_with_prefixed_dialect() {
+        assert_eq!(Version::validate("v1.2.3", Dialect::Npm), Ok(()));
+        assert_eq!(
+            Version::validate("v1.2.x", Dialect::Npm),
+            Err(Error::InvalidCharacter {
+                part: PartType::Patch,
+                byte: b'x',
+                position: 5
+            })
+        );
+        assert_eq!(Version::validate("1.0.post1", Dialect::Pep440), Ok(()));
+    }
+
+    #[test]
+    fn validating_many_versions_with_standard_dialect() {
+        for i in 0..10_000 {
+            let version = match i % 3 {
+                0 => "1.2.3",
+                1 => "10.20.30-rc.1+build.5",
+                _ => "0.0.1-alpha-beta.0",
+            };
+
+            assert!(Version::validate(version, Dialect::Standard).is_ok());
+        }
+    }
+
+    #[test]
+    fn parsing_part_borrows_from_version_string() {
+        let version = b"10.2.1-alpha";
+
+        let (major, remaining, next_part) =
+            Version::parse_part(version, 0, Dialect::Standard, PartType::Major, None).unwrap();
+
+        assert_eq!(major, b"10");
+        assert_eq!(major.as_ptr(), version.as_ptr());
+        assert_eq!(remaining, b"2.1-alpha");
+        assert_eq!(next_part, Some(PartType::Minor));
+
+        let (prerelease, remaining, next_part) =
+            Version::parse_part(b"alpha", 7, Dialect::Standard, PartType::Prerelease, None)
+                .unwrap();
+
+        assert_eq!(prerelease, b"alpha");
+        assert!(remaining.is_empty());
+        assert_eq!(next_part, None);
+    }
+
+    #[test]
+    fn parsing_parts_from_slices_with_standard_dialect() {
+        let version = Version::parse("10.2.1-alpha.1.beta+build.5", Dialect::Standard).unwrap();
+
+        assert_eq!(version.core(), (10, 2, 1));
+        assert_eq!(
+            version.prerelease,
+            Prerelease::Identifier(vec![
+                PrereleaseComponent::String("alpha".to_string()),
+                PrereleaseComponent::Number(1),
+                PrereleaseComponent::String("beta".to_string())
+            ])
+        );
+        assert_eq!(
+            version.build_metadata,
+            BuildMetadata::Identifier("build.5".to_string())
+        );
+        assert_eq!(version.to_string(), "10.2.1-alpha.1.beta+build.5");
+    }
+
+    #[test]
+    fn incrementing_prerelease_with_standard_dialect() {
+        let rc_1 = Version::parse("1.0.0-rc.1+build.5", Dialect::Standard).unwrap();
+        let alpha = Version::parse("1.0.0-alpha", Dialect::Standard).unwrap();
+        let release = Version::parse("1.0.0", Dialect::Standard).unwrap();
+
+        let rc_2 = rc_1.increment_prerelease().unwrap();
+
+        assert_eq!(rc_2.to_string(), "1.0.0-rc.2+build.5");
+        assert!(rc_1 < rc_2);
+
+        assert_eq!(alpha.increment_prerelease(), None);
+        assert_eq!(release.increment_prerelease(), None);
+    }
+
+    #[test]
+    fn comparing_version_with_str_with_standard_dialect() {
+        let version = Version::parse("1.2.3", Dialect::Standard).unwrap();
+
+        assert!(version < "2.0.0");
+        assert!(version > "1.2.3-alpha");
+        assert!(version >= "1.2.3+build");
+        assert!(version == "1.2.3");
+        assert_eq!(version.partial_cmp(&"1.2.3"), Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn comparing_version_with_malformed_str_with_standard_dialect() {
+        let version = Version::parse("1.2.3", Dialect::Standard).unwrap();
+
+        assert_eq!(version.partial_cmp(&"1.2.x"), None);
+        let (is_less, is_greater_or_equal) = (version < "1.2.x", version >= "1.2.x");
+
+        assert!(!is_less);
+        assert!(!is_greater_or_equal);
+        assert!(version != "1.2.x");
+    }
+
+    #[test]
+    fn comparing_version_with_str_uses_version_dialect() {
+        let version = Version::parse("v1.2.3", Dialect::Npm).unwrap();
+
+        assert!(version < "v2.0.0");
+        assert_eq!(
+            Version::parse("1.2.3", Dialect::Standard)
+                .unwrap()
+                .partial_cmp(&"v2.0.0"),
+            None
+        );
+    }
+
+    #[test]
+    fn collecting_every_error_with_standard_dialect() {
+        let errors = Version::parse_collecting("a.001.x", Dialect::Standard).unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![
+                Error::InvalidCharacter {
+                    part: PartType::Major,
+                    byte: b'a',
+                    position: 0
+                },
+                Error::InvalidPrecedingZero(PartType::Minor),
+                Error::InvalidCharacter {
+                    part: PartType::Patch,
+                    byte: b'x',
+                    position: 6
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn collecting_errors_across_parts_with_standard_dialect() {
+        let errors =
+            Version::parse_collecting("1..99999999999999999999999-al%pha+b!", Dialect::Standard)
+                .unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![
+                Error::EmptyPart(PartType::Minor),
+                Error::NumberOverflow(PartType::Patch),
+                Error::InvalidCharacter {
+                    part: PartType::Prerelease,
+                    byte: b'%',
+                    position: 29
+                },
+                Error::InvalidCharacter {
+                    part: PartType::BuildMetadata,
+                    byte: b'!',
+                    position: 35
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn collecting_errors_from_valid_version_with_standard_dialect() {
+        assert_eq!(
+            Version::parse_collecting("1.2.3-alpha.1+build", Dialect::Standard),
+            Ok(Version::parse("1.2.3-alpha.1+build", Dialect::Standard).unwrap())
+        );
+        assert_eq!(
+            Version::parse_collecting("1.0foo", Dialect::Pep440),
+            Err(vec![Version::parse("1.0foo", Dialect::Pep440).unwrap_err()])
+        );
+    }
+
+    #[test]
+    fn canonicalizing_equivalent_versions() {
+        let versions = [
+            Version::parse("1.2.3-rc.1", Dialect::Standard).unwrap(),
+            Version::parse("1.2.3-rc.1+build.5", Dialect::Standard).unwrap(),
+            Version::parse("v1.2.3-rc.1", Dialect::Npm).unwrap(),
+            Version::parse("V1.2.3-rc.1+build", Dialect::Npm).unwrap(),
+            Version::parse("v1.2.3-rc.1+build", Dialect::Go).unwrap(),
+            Version::parse("1.2.3-rc.1+build.1", Dialect::Cargo).unwrap(),
+        ];
+
+        for version in &versions {
+            assert_eq!(version.canonicalize(), "1.2.3-rc.1");
+        }
+
+        assert_eq!(
+            Version::parse("1.2", Dialect::Standard)
+                .unwrap()
+                .canonicalize(),
+            "1.2.0"
+        );
+        assert_eq!(versions[3].to_string(), "V1.2.3-rc.1+build");
+    }
+
+    #[test]
+    fn canonicalizing_keeps_dialect_specific_parts() {
+        for (a, b, dialect) in [
+            ("2!1.0", "1.0", Dialect::Pep440),
+            ("1.0.post1", "1.0", Dialect::Pep440),
+            ("1.2.3.4", "1.2.3", Dialect::NuGet),
+            ("1.2.3.4", "1.2.3", Dialect::RubyGems),
+            ("1:1.0", "1.0", Dialect::Debian),
+            ("1.0-1", "1.0", Dialect::Debian),
+            ("1.0.0.1", "1.0.0", Dialect::Composer),
+            ("1.0.0-patch1", "1.0.0", Dialect::Composer),
+            ("1.21-alpine", "1.21", Dialect::DockerTag),
+            ("latest", "0.0.0", Dialect::DockerTag),
+        ] {
+            let (a, b) = (
+                Version::parse(a, dialect).unwrap(),
+                Version::parse(b, dialect).unwrap(),
+            );
+
+            assert_ne!(a.canonicalize(), b.canonicalize(), "{} and {}", a, b);
+        }
+
+        for (a, b, canonical, dialect) in [
+            ("2!1.0", "2!1.0.0.0+local", "2!1.0.0", Dialect::Pep440),
+            ("1.0-1", "1.0.post1", "1.0.0.post1", Dialect::Pep440),
+            ("1.2.3.0", "1.2.3", "1.2.3", Dialect::NuGet),
+            (
+                "1.2.3.4-beta",
+                "1.2.3.4-beta",
+                "1.2.3.4-beta",
+                Dialect::NuGet,
+            ),
+            ("1-0", "1.pre", "1.pre", Dialect::RubyGems),
+            ("1.0.0", "1", "1", Dialect::RubyGems),
+            ("0:1.01-0", "1.1", "1.1", Dialect::Debian),
+            (
+                "1:1.0~rc01-2",
+                "1:1.0~rc1-02",
+                "1:1.0~rc1-2",
+                Dialect::Debian,
+            ),
+            ("v1.0", "1.0.0.0+build", "1.0.0", Dialect::Composer),
+            (
+                "v1.21-alpine",
+                "1.21.0-alpine",
+                "1.21.0-alpine",
+                Dialect::DockerTag,
+            ),
+        ] {
+            let (a, b) = (
+                Version::parse(a, dialect).unwrap(),
+                Version::parse(b, dialect).unwrap(),
+            );
+
+            assert_eq!(a.canonicalize(), canonical, "{}", a);
+            assert_eq!(b.canonicalize(), canonical, "{}", b);
+        }
+    }
+
+    #[test]
+    fn satisfying_caret_with_standard_dialect() {
+        let parse = |version| Version::parse(version, Dialect::Standard).unwrap();
+
+        assert!(parse("1.4.2").satisfies_caret(&parse("1.2.0")));
+        assert!(parse("1.2.0").satisfies_caret(&parse("1.2.0")));
+        assert!(!parse("1.1.9").satisfies_caret(&parse("1.2.0")));
+        assert!(!parse("2.0.0").satisfies_caret(&parse("1.2.0")));
+
+        // When the major version is zero, the minor version may not change
+        assert!(parse("0.2.9").satisfies_caret(&parse("0.2.3")));
+        assert!(!parse("0.3.0").satisfies_caret(&parse("0.2.3")));
+
+        // When the major and minor versions are zero, the patch version may not change
+        assert!(parse("0.0.3").satisfies_caret(&parse("0.0.3")));
+        assert!(!parse("0.0.4").satisfies_caret(&parse("0.0.3")));
+
+        assert!(!parse("1.5.0-alpha").satisfies_caret(&parse("1.2.0")));
+        assert!(parse("1.2.0").satisfies_caret(&parse("1.2.0-alpha")));
+    }
+
+    #[test]
+    fn satisfying_tilde_with_standard_dialect() {
+        let parse = |version| Version::parse(version, Dialect::Standard).unwrap();
+
+        assert!(parse("1.2.9").satisfies_tilde(&parse("1.2.3")));
+        assert!(!parse("1.2.2").satisfies_tilde(&parse("1.2.3")));
+        assert!(!parse("1.3.0").satisfies_tilde(&parse("1.2.3")));
+        assert!(parse("0.0.5").satisfies_tilde(&parse("0.0.3")));
+    }
+
+    #[test]
+    fn satisfying_caret_across_dialects() {
+        let base = Version::parse("1.2.0", Dialect::Standard).unwrap();
+
+        assert!(!Version::parse("1.4.2", Dialect::Cargo)
+            .unwrap()
+            .satisfies_caret(&base));
+    }
+
+    #[test]
+    fn preserving_case_with_standard_dialect() {
+        let version = Version::parse("1.0.0-Alpha.SHA+Build.FF", Dialect::Standard).unwrap();
+
+        assert_eq!(
+            version.prerelease,
+            Prerelease::Identifier(vec![
+                PrereleaseComponent::String("Alpha".to_string()),
+                PrereleaseComponent::String("SHA".to_string())
+            ])
+        );
+        assert_eq!(
+            version.build_metadata,
+            BuildMetadata::Identifier("Build.FF".to_string())
+        );
+        assert_eq!(version.to_string(), "1.0.0-Alpha.SHA+Build.FF");
+    }
+
+    #[test]
+    fn preserving_case_across_dialects() {
+        for (version, dialect) in [
+            ("1.0.0-rC.1+aBc.DEF", Dialect::Standard),
+            ("1.0.0-rC.1+aBc.DEF", Dialect::Cargo),
+            ("V1.0.0-rC.1+aBc.DEF", Dialect::Npm),
+            ("v1.0.0-rC.1+aBc.DEF", Dialect::Go),
+        ] {
+            assert_eq!(
+                Version::parse(version, dialect).unwrap().to_string(),
+                version
+            );
+        }
+    }
+
+    #[test]
+    fn comparing_case_sensitively_with_standard_dialect() {
+        let uppercase = Version::parse("1.0.0-ALPHA", Dialect::Standard).unwrap();
+        let lowercase = Version::parse("1.0.0-alpha", Dialect::Standard).unwrap();
+
+        // Identifiers are compared in ASCII sort order, where uppercase letters sort first
+        assert_ne!(uppercase, lowercase);
+        assert!(uppercase < lowercase);
+    }
+
+    #[test]
+    fn diffing_versions_with_standard_dialect() {
+        let parse = |version| Version::parse(version, Dialect::Standard).unwrap();
+        let version = parse("1.2.3-alpha.1+build.1");
+
+        assert_eq!(version.diff(&parse("2.0.0")), Some(PartType::Major));
+        assert_eq!(version.diff(&parse("1.3.0")), Some(PartType::Minor));
+        assert_eq!(version.diff(&parse("1.2.4-alpha.1")), Some(PartType::Patch));
+        assert_eq!(
+            version.diff(&parse("1.2.3-alpha.2+build.1")),
+            Some(PartType::Prerelease)
+        );
+        assert_eq!(
+            version.diff(&parse("1.2.3+build.1")),
+            Some(PartType::Prerelease)
+        );
+        assert_eq!(
+            version.diff(&parse("1.2.3-alpha.1+build.2")),
+            Some(PartType::BuildMetadata)
+        );
+        assert_eq!(version.diff(&parse("1.2.3-alpha.1+build.1")), None);
+        assert_eq!(parse("1.3.0").diff(&parse("1.2.3")), Some(PartType::Minor));
+    }
+
+    #[test]
+    fn diffing_versions_with_dialect_specific_parts() {
+        for (a, b, dialect, part) in [
+            ("1.2.3.4", "1.2.3.5", Dialect::NuGet, Some(PartType::Patch)),
+            (
+                "1.2.3.4-beta",
+                "1.2.3.5-alpha",
+                Dialect::NuGet,
+                Some(PartType::Patch),
+            ),
+            ("1.2.3.4", "1.2.4.4", Dialect::NuGet, Some(PartType::Patch)),
+            ("1.2.3", "1.2.3.0", Dialect::NuGet, None),
+            ("1!1.0", "1.0", Dialect::Pep440, Some(PartType::Major)),
+            ("1!1.0", "1!1.1", Dialect::Pep440, Some(PartType::Minor)),
+            ("1.0.0.1", "1.0.0a1", Dialect::Pep440, Some(PartType::Patch)),
+            ("1.0.post1", "1.0", Dialect::Pep440, Some(PartType::Patch)),
+            ("1.0", "1.0.0", Dialect::Pep440, None),
+            ("1:1.0-1", "1.0-1", Dialect::Debian, Some(PartType::Major)),
+            ("1.0-1", "1.0-2", Dialect::Debian, Some(PartType::Patch)),
+        ] {
+            let a = Version::parse(a, dialect).unwrap();
+            let b = Version::parse(b, dialect).unwrap();
+
+            assert_eq!(a.diff(&b), part, "{} {}", a, b);
+            assert_eq!(b.diff(&a), part, "{} {}", b, a);
+            assert_eq!(part.is_none(), a == b, "{} {}", a, b);
+        }
+    }
+
+    #[test]
+    fn defaulting_to_zero_version() {
+        let version = Version::default();
+
+        assert_eq!(version.to_string(), "0.0.0");
+        assert_eq!(version.prerelease, Prerelease::Empty);
+        assert_eq!(version.build_metadata, BuildMetadata::Empty);
+        assert_eq!(version.dialect(), Dialect::Standard);
+        assert!(version < Version::parse("0.0.1", Dialect::Standard).unwrap());
+    }
+
+    #[test]
+    fn bumping_maximum_major_version_overflows() {
+        let version = Version::builder().major(usize::MAX).build().unwrap();
+
+        assert_eq!(
+            version.bump_major_checked(),
+            Err(Error::NumberOverflow(PartType::Major))
+        );
+        assert_eq!(
+            version.bump_minor_checked().unwrap().core(),
+            (usize::MAX, 1, 0)
+        );
+    }
+
+    #[test]
+    fn bumping_maximum_minor_version_overflows() {
+        let version = Version::builder().minor(usize::MAX).build().unwrap();
+
+        assert_eq!(
+            version.bump_minor_checked(),
+            Err(Error::NumberOverflow(PartType::Minor))
+        );
+        assert_eq!(version.bump_major_checked().unwrap().core(), (1, 0, 0));
+    }
+
+    #[test]
+    fn bumping_maximum_patch_version_overflows() {
+        let version = Version::builder().patch(usize::MAX).build().unwrap();
+
+        assert_eq!(
+            version.bump_patch_checked(),
+            Err(Error::NumberOverflow(PartType::Patch))
+        );
+        assert_eq!(version.bump_minor_checked().unwrap().core(), (0, 1, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "the major version part is too large to be incremented")]
+    fn bumping_maximum_major_version_panics() {
+        Version::builder()
+            .major(usize::MAX)
+            .build()
+            .unwrap()
+            .bump_major();
+    }
+
+    #[test]
+    #[should_panic(expected = "the version has no numeric parts to be incremented")]
+    fn bumping_version_without_numeric_parts_panics() {
+        Version::parse("latest", Dialect::DockerTag)
+            .unwrap()
+            .bump_patch();
+    }
+
+    #[test]
+    fn incrementing_maximum_prerelease_overflows() {
+        let version = Version::builder()
+            .prerelease(vec![PrereleaseComponent::Number(usize::MAX)])
+            .build()
+            .unwrap();
+
+        assert_eq!(version.increment_prerelease(), None);
+    }
+
+    #[test]
+    fn attaching_prerelease() {
+        let version = Version::parse("1.2.3+build", Dialect::Standard).unwrap();
+        let prerelease = version.with_prerelease(vec![
+            PrereleaseComponent::String("rc".to_string()),
+            PrereleaseComponent::Number(1),
+        ]);
+
+        assert_eq!(prerelease.to_string(), "1.2.3-rc.1+build");
+        assert_eq!(prerelease.dialect(), Dialect::Standard);
+        assert_eq!(
+            prerelease.with_prerelease(vec![]).to_string(),
+            "1.2.3+build"
+        );
+    }
+
+    #[test]
+    fn attaching_build_metadata() {
+        let version = Version::parse("1.2.3", Dialect::Standard).unwrap();
+
+        assert_eq!(
+            version.with_build_metadata("sha.abc").unwrap().to_string(),
+            "1.2.3+sha.abc"
+        );
+        assert_eq!(
+            Version::parse("1.2.3-rc.1+old", Dialect::Standard)
+                .unwrap()
+                .with_build_metadata("new")
+                .unwrap()
+                .to_string(),
+            "1.2.3-rc.1+new"
+        );
+    }
+
+    #[test]
+    fn attaching_invalid_build_metadata() {
+        let version = Version::parse("1.2.3", Dialect::Standard).unwrap();
+
+        assert_eq!(
+            version.with_build_metadata("sha+abc"),
+            Err(Error::InvalidCharacter {
+                part: PartType::BuildMetadata,
+                byte: b'+',
+                position: 3
+            })
+        );
+        assert_eq!(
+            version.with_build_metadata(""),
+            Err(Error::EmptyPart(PartType::BuildMetadata))
+        );
+    }
+
+    #[test]
+    fn parsing_invalid_utf8_returns_error_in_every_dialect() {
+        for dialect in [
+            Dialect::Standard,
+            Dialect::Cargo,
+            Dialect::Npm,
+            Dialect::Pep440,
+            Dialect::Go,
+            Dialect::Debian,
+            Dialect::RubyGems,
+            Dialect::NuGet,
+            Dialect::Lenient,
+            Dialect::Composer,
+            Dialect::DockerTag,
+            Dialect::Hex,
+        ] {
+            // The Go dialect requires a prefix, which is checked before the invalid byte
+            let version = match dialect {
+                Dialect::Go => &b"v1.\xFF.3"[..],
+                _ => &b"1.\xFF.3"[..],
+            };
+
+            assert_eq!(
+                Version::parse_bytes(version, dialect),
+                Err(Error::InvalidUtf8(PartType::Minor)),
+                "{:?}",
+                dialect
+            );
+        }
+    }
+
+    #[test]
+    fn parsing_bytes_with_invalid_utf8() {
+        for (version, part) in [
+            (&b"\xff.2.3"[..], PartType::Major),
+            (&b"1.\xff.3"[..], PartType::Minor),
+            (&b"1.2.3\xff"[..], PartType::Patch),
+            (&b"1.2.3-alpha.\xff"[..], PartType::Prerelease),
+            (&b"1.2.3-alpha+\xc3"[..], PartType::BuildMetadata),
+        ] {
+            assert_eq!(
+                Version::parse_bytes(version, Dialect::Standard),
+                Err(Error::InvalidUtf8(part))
+            );
+        }
+    }
+
+    #[test]
+    fn parsing_bytes_with_overflow_and_empty_parts() {
+        assert_eq!(
+            Version::parse_bytes(b"1.99999999999999999999999.0", Dialect::Standard),
+            Err(Error::NumberOverflow(PartType::Minor))
+        );
+        assert_eq!(
+            Version::parse_bytes(b"1..0", Dialect::Standard),
+            Err(Error::EmptyPart(PartType::Minor))
+        );
+        assert_eq!(
+            Version::parse_bytes(b"", Dialect::Standard),
+            Err(Error::EmptyPart(PartType::Major))
+        );
+    }
+
+    #[test]
+    fn same_precedence_ignores_build_metadata() {
+        let a = Version::parse("1.0.0+a", Dialect::Standard).unwrap();
+        let b = Version::parse("1.0.0+b", Dialect::Standard).unwrap();
+
+        assert!(a.same_precedence(&b));
+        assert_ne!(a.build_metadata, b.build_metadata);
+        assert!(!a.same_precedence(&Version::parse("1.0.0-a", Dialect::Standard).unwrap()));
+        assert!(!a.same_precedence(&Version::parse("1.0.0", Dialect::Cargo).unwrap()));
+    }
+
+    #[test]
+    fn same_precedence_ignores_significant_build_metadata() {
+        let a = Version::parse("1.0.0+a", Dialect::Cargo).unwrap();
+        let b = Version::parse("1.0.0+b", Dialect::Cargo).unwrap();
+
+        assert_ne!(a, b);
+        assert!(a.same_precedence(&b));
+    }
+
+    #[test]
+    fn parse_default_uses_standard_dialect() {
+        for version in ["1.2.3", "0.1.4-beta+build", "v1.2.3", "1.02.3"] {
+            assert_eq!(
+                Version::parse_default(version),
+                Version::parse(version, Dialect::Standard)
+            );
+        }
+
+        assert_eq!(
+            Version::parse_default("1.2.3").unwrap().dialect(),
+            Dialect::Standard
+        );
+    }
+
+    #[test]
+    fn parse_bytes_matches_parsing_str() {
+        for dialect in [
+            Dialect::Standard,
+            Dialect::Cargo,
+            Dialect::Npm,
+            Dialect::Pep440,
+            Dialect::Go,
+            Dialect::Debian,
+            Dialect::RubyGems,
+            Dialect::NuGet,
+            Dialect::Lenient,
+            Dialect::Composer,
+            Dialect::DockerTag,
+            Dialect::Hex,
+        ] {
+            for version in [
+                "1.2.3",
+                "v1.2.3-alpha.1+build.5",
+                "1.2",
+                "1.02.3",
+                "1.2.3.4",
+                "1!2.0rc1",
+                "1:2.0-1",
+                "1.0.0-é",
+                "",
+            ] {
+                assert_eq!(
+                    Version::parse_bytes(version.as_bytes(), dialect),
+                    Version::parse(version, dialect),
+                    "{} ({:?})",
+                    version,
+                    dialect
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn parse_bytes_reports_the_first_error() {
+        assert_eq!(
+            Version::parse_bytes(b"1.a.3-\xff", Dialect::Standard),
+            Err(Error::InvalidCharacter {
+                part: PartType::Minor,
+                byte: b'a',
+                position: 2
+            })
+        );
+        assert_eq!(
+            Version::parse_bytes(b"1..\xff", Dialect::Standard),
+            Err(Error::EmptyPart(PartType::Minor))
+        );
+        assert_eq!(
+            Version::parse_bytes(b"1.\xff.a", Dialect::Standard),
+            Err(Error::InvalidUtf8(PartType::Minor))
+        );
+    }
+
+    #[test]
+    fn parse_bytes_rejects_invalid_utf8() {
+        for dialect in [Dialect::Standard, Dialect::Pep440] {
+            assert_eq!(
+                Version::parse_bytes(b"1.2.3-\xff", dialect),
+                Err(Error::InvalidUtf8(PartType::Prerelease)),
+                "{:?}",
+                dialect
+            );
+        }
+    }
+
+    #[test]
+    fn max_and_min_follow_dialect_order() {
+        let beta = Version::parse("1.0.0-beta", Dialect::Standard).unwrap();
+        let stable = Version::parse("1.0.0", Dialect::Standard).unwrap();
+
+        assert_eq!(beta.clone().max(stable.clone()).to_string(), "1.0.0");
+        assert_eq!(stable.clone().max(beta.clone()).to_string(), "1.0.0");
+        assert_eq!(beta.clone().min(stable.clone()).to_string(), "1.0.0-beta");
+        assert_eq!(stable.min(beta).to_string(), "1.0.0-beta");
+    }
+
+    #[test]
+    fn max_and_min_return_self_when_equal() {
+        let a = Version::parse("1.0.0+a", Dialect::Standard).unwrap();
+        let b = Version::parse("1.0.0+b", Dialect::Standard).unwrap();
+
+        assert_eq!(a.clone().max(b.clone()).to_string(), "1.0.0+a");
+        assert_eq!(a.clone().min(b.clone()).to_string(), "1.0.0+a");
+        assert_eq!(b.clone().max(a.clone()).to_string(), "1.0.0+b");
+        assert_eq!(a.clone().max(a.clone()), a);
+    }
+
+    #[test]
+    fn cmp_precedence_ignores_build_metadata() {
+        for dialect in [
+            Dialect::Standard,
+            Dialect::Cargo,
+            Dialect::NuGet,
+            Dialect::Pep440,
+        ] {
+            let a = Version::parse("1.0.0-rc.1+a", dialect).unwrap();
+            let b = Version::parse("1.0.0-rc.1+b.2", dialect).unwrap();
+
+            assert_eq!(a.cmp_precedence(&b), Ordering::Equal);
+            assert_eq!(b.cmp_precedence(&a), Ordering::Equal);
+        }
+
+        let a = Version::parse("1.0.0+z", Dialect::Standard).unwrap();
+        let b = Version::parse("1.0.1+a", Dialect::Standard).unwrap();
+
+        assert_eq!(a.cmp_precedence(&b), Ordering::Less);
+    }
+
+    /// A fixed-size buffer, which fails to write once it's full.
+    struct FixedBuffer {
+        bytes: [u8; 32],
+        length: usize,
+    }
+
+    impl core::fmt::Write for FixedBuffer {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let end = self.length + s.len();
+            let bytes = self
+                .bytes
+                .get_mut(self.length..end)
+                .ok_or(core::fmt::Error)?;
+
+            bytes.copy_from_slice(s.as_bytes());
+            self.length = end;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn write_to_writes_into_fixed_buffer() {
+        for (version, dialect) in [
+            ("1.2.3-beta.1+build.5", Dialect::Standard),
+            ("v1.2.3", Dialect::Npm),
+            ("1!2.0rc1", Dialect::Pep440),
+            ("1.2.3.4-beta", Dialect::NuGet),
+        ] {
+            let version = Version::parse(version, dialect).unwrap();
+            let mut buffer = FixedBuffer {
+                bytes: [0; 32],
+                length: 0,
+            };
+
+            version.write_to(&mut buffer).unwrap();
+
+            assert_eq!(
+                core::str::from_utf8(&buffer.bytes[..buffer.length]).unwrap(),
+                version.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn write_to_fails_when_buffer_is_full() {
+        let version =
+            Version::parse("1.2.3-alpha.1.2.3.4.5.6.7.8.9.10.11.12", Dialect::Standard).unwrap();
+        let mut buffer = FixedBuffer {
+            bytes: [0; 32],
+            length: 0,
+        };
+
+        assert_eq!(version.write_to(&mut buffer), Err(core::fmt::Error));
+    }
+
+    #[test]
+    fn rejects_leading_zeros_in_numeric_prerelease() {
+        assert_eq!(
+            Version::parse("1.0.0-alpha.01", Dialect::Standard),
+            Err(Error::InvalidPrecedingZero(PartType::Prerelease))
+        );
+        assert_eq!(
+            Version::validate("1.0.0-alpha.01", Dialect::Standard),
+            Err(Error::InvalidPrecedingZero(PartType::Prerelease))
+        );
+        assert_eq!(
+            Version::parse("1.0.0-alpha.0", Dialect::Standard)
+                .unwrap()
+                .to_string(),
+            "1.0.0-alpha.0"
+        );
+        assert_eq!(
+            Version::parse("1.0.0-alpha.0a", Dialect::Standard)
+                .unwrap()
+                .to_string(),
+            "1.0.0-alpha.0a"
+        );
+    }
+
+    #[test]
+    fn parse_lossy_returns_remaining_suffix() {
+        for (input, dialect, version, remaining) in [
+            ("1.2.3 (stable)", Dialect::Standard, "1.2.3", " (stable)"),
+            ("1.2.3", Dialect::Standard, "1.2.3", ""),
+            (
+                "1.2.3-beta.1 built today",
+                Dialect::Standard,
+                "1.2.3-beta.1",
+                " built today",
+            ),
+            ("v1.2.3, latest", Dialect::Npm, "v1.2.3", ", latest"),
+            ("1.2.3+build!", Dialect::Cargo, "1.2.3+build", "!"),
+        ] {
+            let (parsed, rest) = Version::parse_lossy(input, dialect).unwrap();
+
+            assert_eq!(parsed.to_string(), version, "{}", input);
+            assert_eq!(rest, remaining, "{}", input);
+        }
+    }
+
+    #[test]
+    fn parse_lossy_fails_without_leading_version() {
+        assert_eq!(
+            Version::parse_lossy("stable 1.2.3", Dialect::Standard),
+            Err(Error::InvalidCharacter {
+                part: PartType::Major,
+                byte: b's',
+                position: 0
+            })
+        );
+        assert_eq!(
+            Version::parse_lossy("1.02.3 (stable)", Dialect::Standard),
+            Err(Error::InvalidPrecedingZero(PartType::Minor))
+        );
+    }
+
+    #[test]
+    fn is_compatible_with_shares_major_version() {
+        let version = Version::parse("1.4.0", Dialect::Standard).unwrap();
+
+        assert!(version.is_compatible_with(&Version::parse("1.2.0", Dialect::Standard).unwrap()));
+        assert!(version.is_compatible_with(&Version::parse("1.4.0", Dialect::Standard).unwrap()));
+        assert!(!version.is_compatible_with(&Version::parse("1.5.0", Dialect::Standard).unwrap()));
+        assert!(!version.is_compatible_with(&Version::parse("0.4.0", Dialect::Standard).unwrap()));
+        assert!(!version.is_compatible_with(&Version::parse("2.0.0", Dialect::Standard).unwrap()));
+    }
+
+    #[test]
+    fn is_compatible_with_shares_minor_version_in_initial_development() {
+        let version = Version::parse("0.2.5", Dialect::Standard).unwrap();
+
+        assert!(version.is_compatible_with(&Version::parse("0.2.1", Dialect::Standard).unwrap()));
+        assert!(!version.is_compatible_with(&Version::parse("0.3.0", Dialect::Standard).unwrap()));
+        assert!(!version.is_compatible_with(&Version::parse("0.1.0", Dialect::Standard).unwrap()));
+    }
+
+    #[test]
+    fn parse_strict_requires_every_numeric_part() {
+        assert_eq!(
+            Version::parse_strict("1", Dialect::Standard),
+            Err(Error::MissingPart(PartType::Minor))
+        );
+        assert_eq!(
+            Version::parse_strict("1.2", Dialect::Standard),
+            Err(Error::MissingPart(PartType::Patch))
+        );
+        assert_eq!(
+            Version::parse_strict("v1.2", Dialect::Npm),
+            Err(Error::MissingPart(PartType::Patch))
+        );
+        assert_eq!(
+            Version::parse_strict("1.2.3-beta", Dialect::Standard)
+                .unwrap()
+                .to_string(),
+            "1.2.3-beta"
+        );
+        assert_eq!(
+            Version::parse_strict("1.2.x", Dialect::Standard),
+            Version::parse("1.2.x", Dialect::Standard)
+        );
+    }
+
+    #[test]
+    fn parse_still_defaults_missing_parts() {
+        assert_eq!(
+            Version::parse("1", Dialect::Standard).unwrap().to_string(),
+            "1.0.0"
+        );
+        assert_eq!(
+            Version::parse("1.2", Dialect::Standard)
+                .unwrap()
+                .to_string(),
+            "1.2.0"
+        );
+    }
+
+    #[test]
+    fn validate_fields_accepts_parsed_versions() {
+        for (version, dialect) in [
+            ("1.2.3-rc.1+build.5", Dialect::Standard),
+            ("v1.2.3-alpha", Dialect::Npm),
+            ("1.2.3", Dialect::Cargo),
+        ] {
+            assert_eq!(
+                Version::parse(version, dialect).unwrap().validate_fields(),
+                Ok(())
+            );
+        }
+    }
 
-        let (mut major, mut minor, mut patch, mut prerelease, mut build_metadata) =
-            (vec![], vec![], vec![], vec![], vec![]);
+    #[test]
+    fn validate_fields_rejects_corrupted_fields() {
+        let version = Version::parse("1.2.3-rc.1+build", Dialect::Standard).unwrap();
 
-        let mut current_part_type = PartType::Major;
-        let mut remaining = version_bytes;
-        loop {
-            let part = Version::parse_part(remaining, dialect, current_part_type)?;
+        let mut corrupted = version.clone();
+        corrupted.prerelease = Prerelease::Identifier(vec![
+            PrereleaseComponent::String("rc".to_string()),
+            PrereleaseComponent::String("has space".to_string()),
+        ]);
+        assert_eq!(
+            corrupted.validate_fields(),
+            Err(Error::InvalidCharacter {
+                part: PartType::Prerelease,
+                byte: b' ',
+                position: 3
+            })
+        );
+
+        let mut corrupted = version.clone();
+        corrupted.prerelease = Prerelease::Identifier(vec![]);
+        assert_eq!(
+            corrupted.validate_fields(),
+            Err(Error::EmptyPart(PartType::Prerelease))
+        );
 
-            let (part, r, next_part_type) = part;
+        let mut corrupted = version.clone();
+        corrupted.build_metadata = BuildMetadata::Identifier("a+b".to_string());
+        assert_eq!(
+            corrupted.validate_fields(),
+            Err(Error::InvalidCharacter {
+                part: PartType::BuildMetadata,
+                byte: b'+',
+                position: 1
+            })
+        );
+    }
 
-            match current_part_type {
-                PartType::Major => major = part,
-                PartType::Minor => minor = part,
-                PartType::Patch => patch = part,
-                PartType::Prerelease => prerelease.push(part),
-                PartType::BuildMetadata => build_metadata = part,
-            }
+    #[test]
+    fn cmp_with_prerelease_ranks_orders_listed_components() {
+        let ranks = ["snapshot", "ea", "preview", "rc"];
+        let versions = [
+            "1.0.0-snapshot",
+            "1.0.0-ea",
+            "1.0.0-ea.2",
+            "1.0.0-preview",
+            "1.0.0-preview.1",
+            "1.0.0-rc",
+            "1.0.0",
+            "1.0.1-snapshot",
+        ];
 
-            if next_part_type.is_none() {
-                break;
-            }
+        for pair in versions.windows(2) {
+            let a = Version::parse(pair[0], Dialect::Standard).unwrap();
+            let b = Version::parse(pair[1], Dialect::Standard).unwrap();
 
-            remaining = r;
-            current_part_type = next_part_type.unwrap();
+            assert_eq!(
+                a.cmp_with_prerelease_ranks(&b, &ranks),
+                Ordering::Less,
+                "{} < {}",
+                pair[0],
+                pair[1]
+            );
+            assert_eq!(b.cmp_with_prerelease_ranks(&a, &ranks), Ordering::Greater);
         }
+    }
 
-        return Ok(Version::new(
-            alloc::str::from_utf8(&major[..])
-                .unwrap()
-                .parse::<usize>()
-                .unwrap_or_default(),
-            alloc::str::from_utf8(&minor[..])
-                .unwrap()
-                .parse::<usize>()
-                .unwrap_or_default(),
-            alloc::str::from_utf8(&patch[..])
-                .unwrap()
-                .parse::<usize>()
-                .unwrap_or_default(),
-            if !prerelease.is_empty() {
-                Some(
-                    prerelease
-                        .iter()
-                        .map(|part| {
-                            return if part.iter().all(|i| (&b'0'..=&b'9').contains(&i)) {
-                                PrereleaseComponent::Number(
-                                    alloc::str::from_utf8(&part[..])
-                                        .unwrap()
-                                        .parse::<usize>()
-                                        .unwrap_or_default(),
-                                )
-                            } else {
-                                PrereleaseComponent::String(
-                                    alloc::str::from_utf8(&part[..]).unwrap().to_string(),
-                                )
-                            };
-                        })
-                        .collect(),
-                )
-            } else {
-                None
-            },
-            if !build_metadata.is_empty() {
-                Some(
-                    alloc::str::from_utf8(&build_metadata[..])
-                        .unwrap()
-                        .to_string(),
-                )
-            } else {
-                None
-            },
-            dialect,
-        ));
+    #[test]
+    fn cmp_with_prerelease_ranks_overrides_lexical_order() {
+        let preview = Version::parse("1.0.0-preview", Dialect::Standard).unwrap();
+        let rc = Version::parse("1.0.0-rc", Dialect::Standard).unwrap();
+
+        assert_eq!(
+            preview.cmp_with_prerelease_ranks(&rc, &["preview", "rc"]),
+            Ordering::Less
+        );
+        assert_eq!(
+            preview.cmp_with_prerelease_ranks(&rc, &["rc", "preview"]),
+            Ordering::Greater
+        );
     }
 
-    /// Progressively parse and return one particular part of a version string.
-    ///
-    /// The end point of a part is determined by the chosen dialect.
-    ///
-    /// With each byte parsed the dialect decides whether:
-    /// 1. The byte is valid inside the part (i.e. it's a digit when inside the minor part of a
-    ///    version string).
-    /// 2. Whether to consume the byte, or include
-    fn parse_part(
-        version_bytes: &[u8],
-        dialect: Dialect,
-        current_part: PartType,
-    ) -> Result<(CapturedBytes, &RemainingUnparsedBytes, NextPartType), Error> {
-        let mut part = vec![];
+    #[test]
+    fn cmp_with_prerelease_ranks_falls_back_to_lexical_order() {
+        let alpha = Version::parse("1.0.0-alpha", Dialect::Standard).unwrap();
+        let beta = Version::parse("1.0.0-beta+build", Dialect::Standard).unwrap();
+        let rc = Version::parse("1.0.0-rc", Dialect::Standard).unwrap();
 
-        for (i, byte) in version_bytes.iter().enumerate() {
-            let next_part = match dialect {
-                Standard => dialect::Standard::parse_byte(
-                    byte,
-                    (current_part, &part),
-                    &version_bytes[i + 1..],
-                ),
-            }?;
+        assert_eq!(
+            alpha.cmp_with_prerelease_ranks(&beta, &["rc"]),
+            Ordering::Less
+        );
+        assert_eq!(beta.cmp_with_prerelease_ranks(&rc, &["rc"]), Ordering::Less);
+        assert_eq!(
+            beta.cmp_with_prerelease_ranks(&beta.without_build_metadata(), &[]),
+            Ordering::Equal
+        );
+    }
 
-            if next_part.is_some() {
-                return Ok((part, &version_bytes[i + 1..], next_part));
-            }
+    #[test]
+    fn parse_all_returns_result_for_each_line() {
+        let input = "1.0.0\r\n  1.2.3-beta.1 \n\n\t\n1.02.0\n2.0.0+build\n";
 
-            part.push(byte.to_owned())
-        }
+        assert_eq!(
+            Version::parse_all(input, Dialect::Standard),
+            vec![
+                Ok(Version::parse("1.0.0", Dialect::Standard).unwrap()),
+                Ok(Version::parse("1.2.3-beta.1", Dialect::Standard).unwrap()),
+                Err(Error::InvalidPrecedingZero(PartType::Minor)),
+                Ok(Version::parse("2.0.0+build", Dialect::Standard).unwrap()),
+            ]
+        );
+    }
 
-        Ok((part, &[], None))
+    #[test]
+    fn parse_all_skips_blank_input() {
+        assert!(Version::parse_all("", Dialect::Standard).is_empty());
+        assert!(Version::parse_all(" \n\n\t", Dialect::Standard).is_empty());
     }
 
-    /// Create a new Version instance, using pre-parsed SemVer content.
-    fn new(
-        major: usize,
-        minor: usize,
-        patch: usize,
-        prerelease: Option<Vec<PrereleaseComponent>>,
-        build_metadata: Option<String>,
-        dialect: Dialect,
-    ) -> Version {
-        Version {
-            major,
-            minor,
-            patch,
-            prerelease: if let Some(prerelease) = prerelease {
-                Prerelease::Identifier(prerelease)
-            } else {
-                Prerelease::Empty
-            },
-            build_metadata: if let Some(metadata) = build_metadata {
-                BuildMetadata::Identifier(metadata)
-            } else {
-                BuildMetadata::Empty
-            },
-            dialect,
-        }
+    #[test]
+    fn reporting_rejected_byte_with_standard_dialect() {
+        let error = Version::parse("1.2.%", Dialect::Standard).unwrap_err();
+
+        assert_eq!(
+            error,
+            Error::InvalidCharacter {
+                part: PartType::Patch,
+                byte: b'%',
+                position: 4
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "the patch version part contains an invalid character '%' at byte 4"
+        );
     }
-}
 
-impl PartialEq for Version {
-    fn eq(&self, other: &Self) -> bool {
-        if self.dialect != other.dialect {
-            // Only versions originating from the same dialect can be compared. This
-            // prevents issues with inconsistent comparisons based on comparator order
-            return false;
-        }
+    #[test]
+    fn using_tuple_as_ordered_map_key() {
+        let mut versions = BTreeMap::new();
 
-        match self.dialect {
-            Standard => dialect::Standard::eq(self, other),
+        for version in [
+            "1.0.0",
+            "1.0.0-rc.1+build.5",
+            "0.9.0",
+            "1.0.0-alpha",
+            "1.10.0",
+            "1.2.0",
+        ] {
+            let version = Version::parse(version, Dialect::Standard).unwrap();
+            versions.insert(version.as_tuple(), version);
         }
+
+        let replaced = Version::parse("1.0.0-rc.1+build.6", Dialect::Standard).unwrap();
+        versions.insert(replaced.as_tuple(), replaced);
+
+        assert_eq!(
+            versions
+                .values()
+                .map(|version| version.to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                "0.9.0",
+                "1.0.0-alpha",
+                "1.0.0-rc.1+build.6",
+                "1.0.0",
+                "1.2.0",
+                "1.10.0"
+            ]
+        );
     }
-}
 
-impl PartialOrd for Version {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        if self.dialect != other.dialect {
-            // Only versions originating from the same dialect can be compared. This
-            // prevents issues with inconsistent comparisons based on comparator order
-            return None;
+    #[test]
+    fn parsing_prerelease_starting_with_hyphen() {
+        for (version, dialect, components) in [
+            ("1.0.0--alpha", Dialect::Standard, vec!["-alpha"]),
+            ("1.0.0--alpha+build", Dialect::Cargo, vec!["-alpha"]),
+            ("v1.0.0--alpha.-1", Dialect::Npm, vec!["-alpha", "-1"]),
+            ("v1.0.0---", Dialect::Go, vec!["--"]),
+            ("1.0.0-a--b", Dialect::Lenient, vec!["a--b"]),
+        ] {
+            let parsed = Version::parse(version, dialect).unwrap();
+
+            assert_eq!(
+                parsed.prerelease,
+                Prerelease::Identifier(
+                    components
+                        .into_iter()
+                        .map(|component| PrereleaseComponent::String(component.to_string()))
+                        .collect()
+                ),
+                "{}",
+                version
+            );
+            assert!(Version::validate(version, dialect).is_ok());
         }
+    }
 
-        Some(match self.dialect {
-            Standard => dialect::Standard::cmp(self, other),
-        })
+    #[test]
+    fn round_tripping_prerelease_starting_with_hyphen() {
+        let version = Version::parse("1.0.0--alpha", Dialect::Standard).unwrap();
+        let round_tripped = Version::parse(&version.to_string(), Dialect::Standard).unwrap();
+
+        assert_eq!(version.to_string(), "1.0.0--alpha");
+        assert_eq!(round_tripped, version);
+        assert_eq!(round_tripped.prerelease, version.prerelease);
+
+        // A hyphen sorts before any alphanumeric character
+        assert!(version < Version::parse("1.0.0-alpha", Dialect::Standard).unwrap());
     }
-}
 
-impl Display for Version {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self.dialect {
-                Standard => dialect::Standard::format(self),
-            }
-        )?;
+    #[test]
+    fn normalizing_hand_built_prerelease() {
+        let mut version = Version::parse("1.0.0+build", Dialect::Standard).unwrap();
+        version.prerelease = Prerelease::Identifier(vec![
+            PrereleaseComponent::String("alpha".to_string()),
+            PrereleaseComponent::String("01".to_string()),
+            PrereleaseComponent::Number(2),
+            PrereleaseComponent::String("10".to_string()),
+            PrereleaseComponent::String("0a".to_string()),
+            PrereleaseComponent::String("99999999999999999999999".to_string()),
+        ]);
 
-        Ok(())
+        version.normalize_prerelease();
+
+        assert_eq!(
+            version.prerelease,
+            Prerelease::Identifier(vec![
+                PrereleaseComponent::String("alpha".to_string()),
+                PrereleaseComponent::Number(1),
+                PrereleaseComponent::Number(2),
+                PrereleaseComponent::Number(10),
+                PrereleaseComponent::String("0a".to_string()),
+                PrereleaseComponent::String("99999999999999999999999".to_string()),
+            ])
+        );
+        assert_eq!(
+            version.to_string(),
+            "1.0.0-alpha.1.2.10.0a.99999999999999999999999+build"
+        );
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use alloc::string::ToString;
-    use alloc::vec;
+    #[test]
+    fn normalizing_equivalent_prereleases() {
+        let parsed = Version::parse("1.0.0-alpha.0", Dialect::Standard).unwrap();
+        let mut built = Version::parse("1.0.0", Dialect::Standard).unwrap();
+        built.prerelease = Prerelease::Identifier(vec![
+            PrereleaseComponent::String("alpha".to_string()),
+            PrereleaseComponent::String("00".to_string()),
+        ]);
 
-    use proptest::prelude::*;
+        assert_ne!(built, parsed);
 
-    use crate::dialect::Dialect;
-    use crate::error::Error;
-    use crate::version::{BuildMetadata, PartType, Prerelease, PrereleaseComponent, Version};
+        built.normalize_prerelease();
+        assert_eq!(built, parsed);
+
+        let mut empty = Version::parse("1.0.0", Dialect::Standard).unwrap();
+        empty.normalize_prerelease();
+        assert_eq!(empty.prerelease, Prerelease::Empty);
+    }
 
     #[test]
-    fn parsing_minimal_version_with_standard_dialect() {
-        let version = Version::parse("1", Dialect::Standard).unwrap();
+    fn truncating_to_each_numeric_part() {
+        let version = Version::parse("v1.2.3-rc.1+build", Dialect::Npm).unwrap();
 
-        assert_eq!(version.major, 1);
-        assert_eq!(version.minor, 0);
-        assert_eq!(version.patch, 0);
+        for (part, truncated) in [
+            (PartType::Major, "v1.0.0"),
+            (PartType::Minor, "v1.2.0"),
+            (PartType::Patch, "v1.2.3"),
+        ] {
+            let truncated_version = version.truncate_to(part);
+
+            assert_eq!(truncated_version.to_string(), truncated);
+            assert_eq!(truncated_version.prerelease, Prerelease::Empty);
+            assert_eq!(truncated_version.build_metadata, BuildMetadata::Empty);
+            assert_eq!(truncated_version.dialect(), Dialect::Npm);
+        }
     }
 
     #[test]
-    fn parsing_only_version_with_standard_dialect() {
-        let version = Version::parse("1.2.2", Dialect::Standard).unwrap();
+    fn truncating_groups_release_lines() {
+        let a = Version::parse("1.4.0", Dialect::Standard).unwrap();
+        let b = Version::parse("1.4.7-beta", Dialect::Standard).unwrap();
+        let c = Version::parse("1.5.0", Dialect::Standard).unwrap();
 
-        assert_eq!(version.major, 1);
-        assert_eq!(version.minor, 2);
-        assert_eq!(version.patch, 2);
+        assert_eq!(
+            a.truncate_to(PartType::Minor),
+            b.truncate_to(PartType::Minor)
+        );
+        assert_ne!(
+            a.truncate_to(PartType::Minor),
+            c.truncate_to(PartType::Minor)
+        );
+        assert_eq!(
+            a.truncate_to(PartType::Major),
+            c.truncate_to(PartType::Major)
+        );
     }
 
     #[test]
-    fn parsing_version_with_standard_dialect() {
-        let version = Version::parse("12.19.1-alpha.12+build1234", Dialect::Standard).unwrap();
+    fn truncating_to_prerelease_and_build_metadata() {
+        let version = Version::parse("1.2.3-rc.1+build", Dialect::Standard).unwrap();
 
-        assert_eq!(version.major, 12);
-        assert_eq!(version.minor, 19);
-        assert_eq!(version.patch, 1);
         assert_eq!(
-            version.prerelease,
-            Prerelease::Identifier(vec![
-                PrereleaseComponent::String("alpha".to_string()),
-                PrereleaseComponent::Number(12)
-            ])
+            version.truncate_to(PartType::Prerelease).to_string(),
+            "1.2.3-rc.1"
         );
         assert_eq!(
-            version.build_metadata,
-            BuildMetadata::Identifier("build1234".to_string())
+            version.truncate_to(PartType::BuildMetadata).to_string(),
+            "1.2.3-rc.1+build"
         );
+    }
 
-        assert_eq!("12.19.1-alpha.12+build1234", version.to_string())
+    #[test]
+    fn truncating_keeps_epoch() {
+        for (version, dialect, truncated) in [
+            ("2!1.2.3.4.post1", Dialect::Pep440, "2!1.2.0"),
+            ("2!1.2rc1", Dialect::Pep440, "2!1.2.0"),
+            ("1:1.2.3~rc1-1", Dialect::Debian, "1:1.2.0"),
+        ] {
+            let version = Version::parse(version, dialect).unwrap();
+
+            assert_eq!(version.truncate_to(PartType::Minor).to_string(), truncated);
+            assert!(version.truncate_to(PartType::Major) <= version);
+        }
+
+        let latest = Version::parse("latest", Dialect::DockerTag).unwrap();
+        assert_eq!(latest.truncate_to(PartType::Major), latest);
     }
 
     #[test]
-    fn parsing_version_with_proceeding_major_zero_standard_dialect() {
-        let version = Version::parse("0.1.0", Dialect::Standard).unwrap();
+    fn formatting_preserves_every_prerelease_component() {
+        for version in [
+            "1.0.0-0",
+            "1.0.0-0.0.0",
+            "1.0.0-alpha.0.-.x-y.10",
+            "1.0.0--alpha.-",
+            "1.0.0-0a.a0.00a+0.00.build",
+        ] {
+            assert_eq!(
+                Version::parse(version, Dialect::Standard)
+                    .unwrap()
+                    .to_string(),
+                version
+            );
+        }
+    }
 
-        assert_eq!(version.major, 0);
-        assert_eq!(version.minor, 1);
-        assert_eq!(version.patch, 0);
+    #[test]
+    fn parsing_with_surrounding_whitespace() {
+        for version in [" 1.2.3", "1.2.3 ", "\t 1.2.3-rc.1+build \r\n", "1.2.3"] {
+            assert_eq!(
+                Version::parse_relaxed_whitespace(version, Dialect::Standard).unwrap(),
+                Version::parse(version.trim(), Dialect::Standard).unwrap(),
+                "{:?}",
+                version
+            );
+        }
+
+        assert_eq!(
+            Version::parse(" 1.2.3", Dialect::Standard),
+            Err(Error::InvalidCharacter {
+                part: PartType::Major,
+                byte: b' ',
+                position: 0
+            })
+        );
     }
 
     #[test]
-    fn parsing_version_with_only_build_metadata_standard_dialect() {
-        let version = Version::parse("0.1.12+build.1234", Dialect::Standard).unwrap();
+    fn parsing_with_internal_whitespace_fails() {
+        assert_eq!(
+            Version::parse_relaxed_whitespace("  1. 2.3", Dialect::Standard),
+            Err(Error::InvalidCharacter {
+                part: PartType::Minor,
+                byte: b' ',
+                position: 4
+            })
+        );
+        assert!(Version::parse_relaxed_whitespace("1.2.3 -rc", Dialect::Standard).is_err());
+        assert_eq!(
+            Version::parse_relaxed_whitespace(" \t ", Dialect::Standard),
+            Version::parse("", Dialect::Standard)
+        );
+    }
+
+    #[test]
+    fn failing_on_empty_prerelease_identifiers() {
+        for dialect in [
+            Dialect::Standard,
+            Dialect::Cargo,
+            Dialect::Npm,
+            Dialect::Lenient,
+        ] {
+            for version in [
+                "1.0.0-alpha..1",
+                "1.0.0-",
+                "1.0.0-.1",
+                "1.0.0-alpha.",
+                "1.0.0-+build",
+            ] {
+                assert_eq!(
+                    Version::parse(version, dialect),
+                    Err(Error::EmptyPart(PartType::Prerelease)),
+                    "{} ({:?})",
+                    version,
+                    dialect
+                );
+                assert_eq!(
+                    Version::validate(version, dialect),
+                    Err(Error::EmptyPart(PartType::Prerelease))
+                );
+            }
+        }
 
-        assert_eq!(version.major, 0);
-        assert_eq!(version.minor, 1);
-        assert_eq!(version.patch, 12);
-        assert_eq!(version.prerelease, Prerelease::Empty);
         assert_eq!(
-            version.build_metadata,
-            BuildMetadata::Identifier("build.1234".to_string())
-        )
+            Version::parse("v1.0.0-", Dialect::Go),
+            Err(Error::EmptyPart(PartType::Prerelease))
+        );
+        assert_eq!(
+            Version::parse_collecting("1.0.0-..1", Dialect::Standard),
+            Err(vec![
+                Error::EmptyPart(PartType::Prerelease),
+                Error::EmptyPart(PartType::Prerelease)
+            ])
+        );
     }
 
     #[test]
-    fn parsing_version_with_proceeding_zero_standard_dialect() {
-        let version = Version::parse("12.019.1", Dialect::Standard);
+    fn failing_on_empty_build_metadata_identifiers() {
+        for dialect in [
+            Dialect::Standard,
+            Dialect::Cargo,
+            Dialect::Npm,
+            Dialect::Lenient,
+        ] {
+            for version in [
+                "1.0.0+",
+                "1.0.0+a..b",
+                "1.0.0+.a",
+                "1.0.0+a.",
+                "1.0.0-rc.1+",
+            ] {
+                assert_eq!(
+                    Version::parse(version, dialect),
+                    Err(Error::EmptyPart(PartType::BuildMetadata)),
+                    "{} ({:?})",
+                    version,
+                    dialect
+                );
+                assert_eq!(
+                    Version::validate(version, dialect),
+                    Err(Error::EmptyPart(PartType::BuildMetadata))
+                );
+            }
 
-        let Err(error) = version else {
-            panic!("Parsing should have returned an error")
-        };
+            assert_eq!(
+                Version::parse("1.0.0+a.b", dialect)
+                    .unwrap()
+                    .build_metadata
+                    .components(),
+                vec!["a", "b"]
+            );
+        }
 
-        assert_eq!(error, Error::InvalidPrecedingZero(PartType::Minor))
+        let version = Version::parse("1.0.0", Dialect::Standard).unwrap();
+        assert_eq!(
+            version.with_build_metadata("a..b"),
+            Err(Error::EmptyPart(PartType::BuildMetadata))
+        );
+    }
+
+    #[test]
+    fn detecting_initial_development() {
+        for (version, is_initial_development) in [
+            ("0.9.0", true),
+            ("0.0.1", true),
+            ("0.1.0-alpha", true),
+            ("1.0.0", false),
+            ("1.0.0-rc.1", false),
+            ("10.0.0", false),
+        ] {
+            assert_eq!(
+                Version::parse(version, Dialect::Standard)
+                    .unwrap()
+                    .is_initial_development(),
+                is_initial_development,
+                "{}",
+                version
+            );
+        }
     }
 
     proptest! {
         #[test]
         fn parses_various_valid_version_strings(
-            version in "(0|[1-9][0-9]*)[.](0|[1-9][0-9]*)[.](0|[1-9][0-9]*)([-](0|[1-9A-Za-z-][0-9A-Za-z-]*)([.][0-9A-Za-z-]+)*)?([+][0-9A-Za-z-]+([.][0-9A-Za-z-]+)*)?"
+            version in "(0|[1-9][0-9]{0,15})[.](0|[1-9][0-9]{0,15})[.](0|[1-9][0-9]{0,15})([-](0|[1-9][0-9]{0,15}|[0-9]*[A-Za-z-][0-9A-Za-z-]*)([.](0|[1-9][0-9]{0,15}|[0-9]*[A-Za-z-][0-9A-Za-z-]*))*)?([+][0-9A-Za-z-]+([.][0-9A-Za-z-]+)*)?"
         ) {
             let version = Version::parse(&version, Dialect::Standard);
 
             assert!(version.is_ok())
         }
+
+        #[test]
+        fn formats_valid_version_strings_unchanged(
+            version in "(0|[1-9][0-9]{0,15})[.](0|[1-9][0-9]{0,15})[.](0|[1-9][0-9]{0,15})([-](0|[1-9][0-9]{0,15}|[0-9]*[A-Za-z-][0-9A-Za-z-]*)([.](0|[1-9][0-9]{0,15}|[0-9]*[A-Za-z-][0-9A-Za-z-]*))*)?([+][0-9A-Za-z-]+([.][0-9A-Za-z-]+)*)?"
+        ) {
+            for dialect in [Dialect::Standard, Dialect::Cargo, Dialect::Npm, Dialect::Lenient] {
+                let parsed = Version::parse(&version, dialect).unwrap();
+
+                assert_eq!(parsed.to_string(), version);
+                assert_eq!(Version::parse(&parsed.to_string(), dialect).unwrap(), parsed);
+            }
+        }
+
+        #[test]
+        fn parsing_arbitrary_bytes_never_panics(version in proptest::collection::vec(any::<u8>(), 0..32)) {
+            for dialect in [
+                Dialect::Standard,
+                Dialect::Cargo,
+                Dialect::Npm,
+                Dialect::Pep440,
+                Dialect::Go,
+                Dialect::Debian,
+                Dialect::RubyGems,
+                Dialect::NuGet,
+                Dialect::Lenient,
+                Dialect::Composer,
+                Dialect::DockerTag,
+                Dialect::Hex,
+            ] {
+                let _ = Version::try_parse_bytes(&version, dialect);
+            }
+        }
+
+        #[test]
+        fn parsing_arbitrary_version_like_bytes_never_panics(version in "[0-9a-zA-Z.+~:!_ -]{0,24}") {
+            for dialect in [
+                Dialect::Standard,
+                Dialect::Cargo,
+                Dialect::Npm,
+                Dialect::Pep440,
+                Dialect::Go,
+                Dialect::Debian,
+                Dialect::RubyGems,
+                Dialect::NuGet,
+                Dialect::Lenient,
+                Dialect::Composer,
+                Dialect::DockerTag,
+                Dialect::Hex,
+            ] {
+                let _ = Version::parse_bytes(version.as_bytes(), dialect);
+            }
+        }
+
+        #[test]
+        fn canonicalizes_versions_of_equal_precedence_only(
+            a in "[0-9a.~:!+-]{1,8}",
+            b in "[0-9a.~:!+-]{1,8}",
+        ) {
+            for dialect in [
+                Dialect::Standard,
+                Dialect::Cargo,
+                Dialect::Npm,
+                Dialect::Pep440,
+                Dialect::Go,
+                Dialect::Debian,
+                Dialect::RubyGems,
+                Dialect::NuGet,
+                Dialect::Lenient,
+                Dialect::Composer,
+                Dialect::DockerTag,
+                Dialect::Hex,
+            ] {
+                if let (Ok(a), Ok(b)) = (Version::parse(&a, dialect), Version::parse(&b, dialect)) {
+                    assert_eq!(
+                        a.cmp_precedence(&b) == Ordering::Equal,
+                        a.canonicalize() == b.canonicalize(),
+                        "{} and {}",
+                        a,
+                        b
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn diffs_every_pair_of_unequal_versions(
+            a in "[0-9a.~:!+-]{1,8}",
+            b in "[0-9a.~:!+-]{1,8}",
+        ) {
+            for dialect in [
+                Dialect::Standard,
+                Dialect::Cargo,
+                Dialect::Npm,
+                Dialect::Pep440,
+                Dialect::Go,
+                Dialect::Debian,
+                Dialect::RubyGems,
+                Dialect::NuGet,
+                Dialect::Lenient,
+                Dialect::Composer,
+                Dialect::DockerTag,
+                Dialect::Hex,
+            ] {
+                if let (Ok(a), Ok(b)) = (Version::parse(&a, dialect), Version::parse(&b, dialect)) {
+                    if a != b {
+                        assert!(a.diff(&b).is_some(), "{} and {}", a, b);
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn validates_the_same_as_parsing(version in "[0-9a-z.+-]{0,12}") {
+            assert_eq!(
+                Version::validate(&version, Dialect::Standard),
+                Version::parse(&version, Dialect::Standard).map(|_| ())
+            );
+        }
     }
 }
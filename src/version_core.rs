@@ -0,0 +1,77 @@
+use core::fmt::{Display, Formatter};
+
+/// The numeric core of a version (like `1.2.3` in `1.2.3-alpha+build`), without any prerelease
+/// identifier or build metadata.
+///
+/// Unlike a [`Version`](crate::Version), a core never allocates, and is ordered numerically regardless
+/// of the dialect the version was parsed with, which makes it a cheap key for maps and sets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VersionCore {
+    pub major: usize,
+    pub minor: usize,
+    pub patch: usize,
+}
+
+impl Display for VersionCore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeSet;
+    use alloc::string::ToString;
+
+    use crate::{Dialect, Version, VersionCore};
+
+    #[test]
+    fn should_order_numerically() {
+        let versions = ["0.9.10", "1.0.0", "1.2.0", "1.10.0", "1.10.2", "2.0.0"];
+
+        for pair in versions.windows(2) {
+            let a = Version::parse(pair[0], Dialect::Standard).unwrap();
+            let b = Version::parse(pair[1], Dialect::Standard).unwrap();
+
+            assert!(
+                a.core_version() < b.core_version(),
+                "{} < {}",
+                pair[0],
+                pair[1]
+            );
+        }
+    }
+
+    #[test]
+    fn should_ignore_prerelease_and_build_metadata() {
+        let a = Version::parse("1.2.3-alpha+build", Dialect::Standard).unwrap();
+        let b = Version::parse("1.2.3", Dialect::Cargo).unwrap();
+
+        assert_eq!(a.core_version(), b.core_version());
+        assert_eq!(
+            [a.core_version(), b.core_version()]
+                .into_iter()
+                .collect::<BTreeSet<_>>()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn should_display_numeric_parts() {
+        let core = Version::parse("v1.2.3-alpha+build", Dialect::Npm)
+            .unwrap()
+            .core_version();
+
+        assert_eq!(core.to_string(), "1.2.3");
+        assert_eq!(
+            VersionCore {
+                major: 0,
+                minor: 10,
+                patch: 200
+            }
+            .to_string(),
+            "0.10.200"
+        );
+    }
+}
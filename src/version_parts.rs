@@ -0,0 +1,51 @@
+use crate::{BuildMetadata, Prerelease};
+
+/// A snapshot of every part of a version, in canonical order, borrowed from a
+/// [`Version`](crate::Version).
+///
+/// Parts specific to a particular dialect (like a PEP 440 epoch) are not included.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VersionParts<'a> {
+    pub major: usize,
+    pub minor: usize,
+    pub patch: usize,
+    pub prerelease: &'a Prerelease,
+    pub build_metadata: &'a BuildMetadata,
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    use crate::{BuildMetadata, Dialect, Prerelease, PrereleaseComponent, Version, VersionParts};
+
+    #[test]
+    fn should_snapshot_every_part() {
+        let version = Version::parse("1.2.3-rc.1+b", Dialect::Standard).unwrap();
+
+        assert_eq!(
+            version.components(),
+            VersionParts {
+                major: 1,
+                minor: 2,
+                patch: 3,
+                prerelease: &Prerelease::Identifier(vec![
+                    PrereleaseComponent::String("rc".to_string()),
+                    PrereleaseComponent::Number(1),
+                ]),
+                build_metadata: &BuildMetadata::Identifier("b".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn should_snapshot_empty_parts() {
+        let version = Version::parse("0.1", Dialect::Standard).unwrap();
+        let parts = version.components();
+
+        assert_eq!((parts.major, parts.minor, parts.patch), (0, 1, 0));
+        assert_eq!(parts.prerelease, &Prerelease::Empty);
+        assert_eq!(parts.build_metadata, &BuildMetadata::Empty);
+    }
+}